@@ -1,6 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 use anchor_spl::token::{self, Token, TokenAccount, Mint, burn};
+use anchor_spl::associated_token::AssociatedToken;
+use mpl_token_metadata::instructions::{
+    BurnNftCpiBuilder, CreateMasterEditionV3CpiBuilder, CreateMetadataAccountV3CpiBuilder,
+    UpdateMetadataAccountV2CpiBuilder,
+};
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::types::DataV2;
+
+mod math;
 
 declare_id!("2gfJsBnr74xVFPhgxJFAfZLvy8y3FnUDppcRoEGsQ3dm");
 
@@ -31,19 +40,22 @@ pub mod universe_solana_backend {
 
     /// Initialize the main configuration for Universe Solana
     /// This should be called by the admin after token creation via Pinksale
-    pub fn initialize(ctx: Context<Initialize>, token_mint: Pubkey) -> Result<()> {
+    /// Config is a singleton at PDA ["config"]; the reward pool is a program-owned vault at PDA ["reward_pool"]
+    /// Only the program's upgrade authority can initialize, and becomes the admin
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         let config = &mut ctx.accounts.config;
         
         // Set basic configuration parameters from gitbook
         config.total_supply = 1_000_000_000 * 10u64.pow(9); // 1 billion $UNIV with 9 decimals
         config.planet_creation_cost = 1_000 * 10u64.pow(9); // 1,000 $UNIV to create a planet
         config.max_planets_per_user = 10; // Maximum 10 planets per user
-        config.reward_rate = 4; // 4% daily rewards
+        config.reward_rate = 4; // 4% base daily rewards, raised per tier by the tier table
         config.reward_interval = 28_800; // 8 hours in seconds
         config.admin = ctx.accounts.admin.key();
+        config.bump = ctx.bumps.config;
         
         // Store token mint
-        config.token_mint = token_mint;
+        config.token_mint = ctx.accounts.token_mint.key();
         
         // Set up wallet addresses
         config.reward_pool = ctx.accounts.reward_pool.key();
@@ -68,22 +80,59 @@ pub mod universe_solana_backend {
         config.ecosystem_vesting_duration = 365 * 24 * 60 * 60; // 1 year in seconds
         config.treasury_vesting_duration = 365 * 24 * 60 * 60; // 1 year in seconds
         
+        // Planet IDs start at zero
+        config.planet_count = 0;
+        
+        // Exit parameters for decommissioning planets
+        config.early_exit_penalty_rate = 10; // 10% penalty for early exits
+        config.early_exit_period = 30 * 24 * 60 * 60; // 30 days in seconds
+        config.downgrade_rule = DowngradeRule::OneLevel; // Partial withdrawals drop one level
+        
+        // Solvency parameters for the reward pool
+        config.total_locked_tokens = 0;
+        config.reserve_ratio_bps = 10_000; // Locked tokens are fully reserved
+        
+        // Reward accounting starts on per-planet rates; the accumulator
+        // needs an emission rate before it is switched on
+        config.reward_mode = RewardMode::PerPlanetRate;
+        config.emission_rate = 0;
+        config.acc_reward_per_share = 0;
+        config.total_shares = 0;
+        config.last_reward_time = Clock::get()?.unix_timestamp;
+        
+        // Unclaimed rewards stop growing after a week
+        config.max_accrual_window = 7 * 24 * 60 * 60; // 7 days in seconds
+        config.accrual_cap_mode = AccrualCapMode::Hold;
+        config.interval_mode = IntervalMode::Continuous; // Partial intervals are paid
+        config.rate_unit = RateUnit::PerDay; // Rates are daily, as in the gitbook
+        config.daily_rate_bps = 400; // 4% a day
+        config.interval_rate_bps = 133; // 4% a day spread over three 8 hour intervals
+        
         msg!("Universe Solana initialized with config: {:?}", config);
+        emit_config_updated(config);
         Ok(())
     }
     
     /// Initialize a new user account
+    /// Each wallet has exactly one user account at PDA ["user", wallet]
     pub fn initialize_user(ctx: Context<InitializeUser>) -> Result<()> {
         let user = &mut ctx.accounts.user;
         user.authority = ctx.accounts.authority.key();
         user.planets = Vec::new();
+        user.bump = ctx.bumps.user;
         
         msg!("User initialized: {:?}", user.authority);
+        emit!(UserInitialized {
+            authority: user.authority,
+            user: user.key(),
+        });
         Ok(())
     }
     
     /// Set up vesting for locked tokens
     /// This should be called after token creation and initial distribution
+    /// Creates the vesting vault at PDA ["vesting_vault"], which must then be funded
+    /// with the ecosystem and treasury allocations
     pub fn setup_vesting(
         ctx: Context<SetupVesting>, 
         ecosystem_amount: u64, 
@@ -103,15 +152,52 @@ pub mod universe_solana_backend {
         
         msg!("Vesting initialized: Ecosystem: {}, Treasury: {}, Burn Reserve: {}", 
             ecosystem_amount, treasury_amount, burn_reserve_amount);
+        emit!(VestingSetUp {
+            vesting: vesting.key(),
+            ecosystem_amount,
+            treasury_amount,
+            burn_reserve_amount,
+            timestamp: vesting.last_claim_time,
+        });
+        Ok(())
+    }
+
+    /// Create the planet tier table with the default Earth-to-Sun tiers (admin only)
+    pub fn initialize_tier_table(ctx: Context<InitializeTierTable>) -> Result<()> {
+        let tier_table = &mut ctx.accounts.tier_table;
+        tier_table.bump = ctx.bumps.tier_table;
+        tier_table.tiers = get_default_tiers();
+        
+        msg!("Tier table initialized with {} tiers", tier_table.tiers.len());
+        for (index, tier) in tier_table.tiers.iter().enumerate() {
+            emit!(TierUpdated {
+                index: index as u8,
+                tier: tier.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Create the emission schedule with no segments, i.e. flat full-rate emissions (admin only)
+    pub fn initialize_emission_schedule(ctx: Context<InitializeEmissionSchedule>) -> Result<()> {
+        let emission_schedule = &mut ctx.accounts.emission_schedule;
+        emission_schedule.bump = ctx.bumps.emission_schedule;
+        emission_schedule.segments = Vec::new();
+        
+        msg!("Emission schedule initialized");
+        emit!(EmissionScheduleUpdated {
+            segments: emission_schedule.segments.clone(),
+        });
         Ok(())
     }
 
     /*** CORE FUNCTIONALITY ***/
     
     /// Create a new planet (costs 1,000 $UNIV)
+    /// Planets live at PDA ["planet", planet_id] with sequential ids from Config.planet_count
     pub fn create_planet(ctx: Context<CreatePlanet>) -> Result<()> {
         let user = &mut ctx.accounts.user;
-        let config = &ctx.accounts.config;
+        let config = &mut ctx.accounts.config;
         let planet_account = &mut ctx.accounts.planet_account;
         let token_account = &ctx.accounts.token_account;
         let clock = Clock::get()?;
@@ -122,16 +208,31 @@ pub mod universe_solana_backend {
             ErrorCode::MaxPlanetsReached
         );
 
-        // Create planet account - starting as Earth
-        planet_account.owner = user.key();
+        // Create planet account - starting at the first tier (Earth)
+        let tier = get_tier_for_level(&ctx.accounts.tier_table, 0)?;
         planet_account.compound_level = 0;
-        planet_account.daily_reward = 4; // Starting reward is 4%
+        planet_account.compound_count = 0;
+        planet_account.prestige = 0;
+        planet_account.daily_reward = get_tier_reward_bps(config, tier);
+        planet_account.rate_unit = config.rate_unit;
         planet_account.last_claim = clock.unix_timestamp;
         planet_account.locked_tokens = config.planet_creation_cost;
-        planet_account.name = "Earth".to_string();
+        planet_account.name = tier.name.clone();
+        planet_account.created_at = clock.unix_timestamp;
+        
+        // Assign the next sequential ID to this planet
+        planet_account.planet_id = config.planet_count;
+        planet_account.bump = ctx.bumps.planet_account;
+        planet_account.mint = ctx.accounts.planet_mint.key();
+        config.planet_count = math::checked_add(config.planet_count, 1)?;
+        config.total_locked_tokens = math::checked_add(config.total_locked_tokens, config.planet_creation_cost)?;
         
-        // Generate unique ID for this planet
-        planet_account.planet_id = planet_account.key();
+        // Give the planet its weighted shares of accumulator rewards
+        planet_account.shares = 0;
+        planet_account.reward_debt = 0;
+        planet_account.owed_rewards = 0;
+        update_reward_index(config, clock.unix_timestamp)?;
+        reweight_planet(planet_account, config)?;
         
         // Transfer tokens from user to reward pool
         let cpi_accounts = token::Transfer {
@@ -140,38 +241,108 @@ pub mod universe_solana_backend {
             authority: ctx.accounts.authority.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
         token::transfer(cpi_ctx, config.planet_creation_cost)?;
 
-        // For NFT creation, we'd normally integrate with Metaplex here
-        // In testing phase, we'll just simulate this and log the event
-        msg!("NFT would be created for planet: {}, Symbol: UNIV-PLANET, URI: https://universe-solana.com/metadata/{}.json", 
-             planet_account.name, planet_account.key());
+        // Create PDA signer for planet NFT operations
+        let authority_seeds = &[
+            b"authority".as_ref(),
+            &[config.authority_bump],
+        ];
+        let signer = &[&authority_seeds[..]];
+
+        // Mint the single planet NFT to the creator
+        let cpi_accounts = token::MintTo {
+            mint: ctx.accounts.planet_mint.to_account_info(),
+            to: ctx.accounts.planet_token_account.to_account_info(),
+            authority: ctx.accounts.program_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::mint_to(cpi_ctx, 1)?;
+
+        // Create Metaplex metadata with the authority PDA as update authority
+        let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
+        let metadata = ctx.accounts.metadata.to_account_info();
+        let master_edition = ctx.accounts.master_edition.to_account_info();
+        let planet_mint = ctx.accounts.planet_mint.to_account_info();
+        let program_authority = ctx.accounts.program_authority.to_account_info();
+        let payer = ctx.accounts.authority.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let rent = ctx.accounts.rent.to_account_info();
+        let data = get_planet_metadata(tier, planet_account.compound_level);
+
+        CreateMetadataAccountV3CpiBuilder::new(&token_metadata_program)
+            .metadata(&metadata)
+            .mint(&planet_mint)
+            .mint_authority(&program_authority)
+            .payer(&payer)
+            .update_authority(&program_authority, true)
+            .system_program(&system_program)
+            .rent(Some(&rent))
+            .data(data.clone())
+            .is_mutable(true)
+            .invoke_signed(signer)?;
+
+        // Create master edition with zero supply so the planet stays a 1-of-1
+        CreateMasterEditionV3CpiBuilder::new(&token_metadata_program)
+            .edition(&master_edition)
+            .mint(&planet_mint)
+            .update_authority(&program_authority)
+            .mint_authority(&program_authority)
+            .payer(&payer)
+            .metadata(&metadata)
+            .token_program(&token_program)
+            .system_program(&system_program)
+            .rent(Some(&rent))
+            .max_supply(0)
+            .invoke_signed(signer)?;
+
+        msg!("NFT created for planet: {}, Mint: {}, Symbol: {}, URI: {}", 
+             planet_account.name, planet_account.mint, data.symbol, data.uri);
         
-        // Add planet to user's list
+        // Track the planet in the user's slots for the per-user limit
+        // (ownership itself follows whoever holds the planet NFT)
         user.planets.push(planet_account.key());
         
         msg!("Planet created for user {:?}", user.authority);
+        emit!(PlanetCreated {
+            owner: user.authority,
+            planet: planet_account.key(),
+            planet_id: planet_account.planet_id,
+            mint: planet_account.mint,
+            locked_tokens: planet_account.locked_tokens,
+            timestamp: clock.unix_timestamp,
+        });
         Ok(())
     }
 
     /// Claim rewards (requires 8 hours since last claim)
+    /// Rewards are rationed to the pool balance above the required reserve;
+    /// any shortfall is forfeited. The planet is then rebased onto the current rates.
     pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         let planet = &mut ctx.accounts.planet_account;
-        let config = &ctx.accounts.config;
+        let config = &mut ctx.accounts.config;
         let clock = Clock::get()?;
-        let elapsed = clock.unix_timestamp - planet.last_claim;
-
-        // Check if reward interval has passed
+        // Check if reward interval has passed or rewards are banked
         require!(
-            elapsed >= config.reward_interval as i64,
+            is_reward_ready(planet, config, clock.unix_timestamp),
             ErrorCode::RewardNotReady
         );
 
         // Calculate reward based on compound level and elapsed time
-        let intervals = elapsed as f64 / config.reward_interval as f64;
-        let daily_rate = planet.daily_reward as f64 / 100.0; // Convert percentage to decimal
-        let reward = (daily_rate * planet.locked_tokens as f64) * intervals;
+        let pending = calculate_pending_reward(
+            planet,
+            config,
+            &ctx.accounts.emission_schedule,
+            clock.unix_timestamp,
+        )?;
+        let reward = ration_reward(
+            config,
+            ctx.accounts.reward_pool.amount,
+            pending,
+            clock.unix_timestamp,
+        )?;
         
         // Create PDA signer for reward pool
         let authority_seeds = &[
@@ -192,69 +363,355 @@ pub mod universe_solana_backend {
             cpi_accounts,
             signer,
         );
-        token::transfer(cpi_ctx, reward as u64)?;
+        token::transfer(cpi_ctx, reward)?;
 
-        // Update last claim timestamp
-        planet.last_claim = clock.unix_timestamp;
+        // Update last claim timestamp, settle accumulator rewards and rebase the rate
+        planet.last_claim = get_next_last_claim(planet, config, clock.unix_timestamp);
+        update_reward_index(config, clock.unix_timestamp)?;
+        settle_planet_rewards(planet, config)?;
+        rebase_planet(planet, config, &ctx.accounts.tier_table)?;
         
         msg!("Claimed {} reward tokens", reward);
+        emit!(RewardsClaimed {
+            owner: ctx.accounts.owner.key(),
+            planet: planet.key(),
+            amount: reward,
+            recipient: ctx.accounts.user_token_account.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Claim rewards from every ready planet in a single transfer
+    /// Remaining accounts are (planet, planet NFT token account) pairs; planets
+    /// that are not ready yet are skipped. When the pool is low every planet's
+    /// reward is scaled down by the same ratio.
+    pub fn claim_all_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAllRewards<'info>>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let owner = ctx.accounts.owner.key();
+        let clock = Clock::get()?;
+
+        let pairs = ctx.remaining_accounts.chunks_exact(2);
+        require!(
+            !ctx.remaining_accounts.is_empty() && pairs.remainder().is_empty(),
+            ErrorCode::InvalidRemainingAccounts
+        );
+
+        let mut total_reward: u64 = 0;
+        let mut claimed = Vec::new();
+        update_reward_index(config, clock.unix_timestamp)?;
+
+        for accounts in pairs {
+            let mut planet = load_owned_planet(&accounts[0], &accounts[1], &owner)?;
+            // Skip planets that are not ready instead of failing the batch
+            if !is_reward_ready(&planet, config, clock.unix_timestamp) {
+                continue;
+            }
+
+            let reward = calculate_pending_reward(
+                &planet,
+                config,
+                &ctx.accounts.emission_schedule,
+                clock.unix_timestamp,
+            )?;
+            total_reward = math::checked_add(total_reward, reward)?;
+
+            // Update last claim timestamp, settle accumulator rewards, rebase the rate
+            // and persist the planet
+            planet.last_claim = get_next_last_claim(&planet, config, clock.unix_timestamp);
+            settle_planet_rewards(&mut planet, config)?;
+            rebase_planet(&mut planet, config, &ctx.accounts.tier_table)?;
+            planet.exit(&crate::ID)?;
+            claimed.push((planet.key(), reward));
+        }
+
+        require!(!claimed.is_empty(), ErrorCode::RewardNotReady);
+
+        // Ration the combined reward and split it pro rata across planets
+        let paid = ration_reward(
+            config,
+            ctx.accounts.reward_pool.amount,
+            total_reward,
+            clock.unix_timestamp,
+        )?;
+        for (planet, reward) in &claimed {
+            emit!(RewardsClaimed {
+                owner,
+                planet: *planet,
+                amount: if paid == total_reward {
+                    *reward
+                } else {
+                    math::mul_div(*reward, paid, total_reward)?
+                },
+                recipient: ctx.accounts.user_token_account.key(),
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        // Create PDA signer for reward pool
+        let authority_seeds = &[
+            b"authority".as_ref(),
+            &[config.authority_bump],
+        ];
+        let signer = &[&authority_seeds[..]];
+
+        // Transfer the combined reward from pool to user
+        let cpi_accounts = token::Transfer {
+            from: ctx.accounts.reward_pool.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(
+            cpi_program,
+            cpi_accounts,
+            signer,
+        );
+        token::transfer(cpi_ctx, paid)?;
+
+        msg!("Claimed {} reward tokens from {} planets", paid, claimed.len());
         Ok(())
     }
 
     /// Compound rewards to upgrade planet
+    /// Compounded rewards become locked tokens, so they are rationed like claims
     pub fn compound_rewards(ctx: Context<CompoundRewards>) -> Result<()> {
         let planet = &mut ctx.accounts.planet_account;
-        let config = &ctx.accounts.config;
+        let config = &mut ctx.accounts.config;
         let clock = Clock::get()?;
-        let elapsed = clock.unix_timestamp - planet.last_claim;
-
-        // Check if reward interval has passed
+        // Check if reward interval has passed or rewards are banked
         require!(
-            elapsed >= config.reward_interval as i64,
+            is_reward_ready(planet, config, clock.unix_timestamp),
             ErrorCode::RewardNotReady
         );
 
-        // Calculate reward
-        let intervals = elapsed as f64 / config.reward_interval as f64;
-        let daily_rate = planet.daily_reward as f64 / 100.0;
-        let reward = (daily_rate * planet.locked_tokens as f64) * intervals;
+        // Add rewards to locked tokens and evolve the planet
+        let tier_table = &ctx.accounts.tier_table;
+        let previous_level = planet.compound_level;
+        let reward = compound_planet(
+            planet,
+            config,
+            tier_table,
+            &ctx.accounts.emission_schedule,
+            ctx.accounts.reward_pool.amount,
+            clock.unix_timestamp,
+        )?;
         
-        // Add rewards to locked tokens
-        planet.locked_tokens += reward as u64;
-        
-        // Increase compound level
-        planet.compound_level += 1;
-        
-        // Update daily reward based on compound level
-        planet.daily_reward = get_reward_for_level(planet.compound_level)?;
-        
-        // Update planet name based on compound level
-        planet.name = get_planet_name_for_level(planet.compound_level);
-        
-        // For NFT metadata updates, we'd integrate with Metaplex here
-        // In testing phase, we'll just simulate this and log the event
-        msg!("NFT metadata would be updated for planet: {}, Symbol: UNIV-PLANET-{}, URI: https://universe-solana.com/metadata/{}.json", 
-             planet.name, planet.compound_level, planet.key());
-        
-        // Update last claim timestamp
-        planet.last_claim = clock.unix_timestamp;
+        // Update NFT metadata to follow the planet evolution
+        if planet.compound_level != previous_level {
+            update_planet_metadata(
+                &ctx.accounts.token_metadata_program,
+                &ctx.accounts.metadata,
+                &ctx.accounts.program_authority,
+                config.authority_bump,
+                planet,
+                tier_table,
+            )?;
+        }
         
-        msg!("Compounded rewards. New locked tokens: {}, New compound level: {}, New daily reward: {}%, Planet: {}",
+        msg!("Compounded rewards. New locked tokens: {}, New compound level: {}, New daily reward: {} bps, Planet: {}",
             planet.locked_tokens, planet.compound_level, planet.daily_reward, planet.name);
+        emit!(RewardsCompounded {
+            owner: ctx.accounts.authority.key(),
+            planet: planet.key(),
+            reward,
+            locked_tokens: planet.locked_tokens,
+            compound_level: planet.compound_level,
+            daily_reward: planet.daily_reward,
+            prestige: planet.prestige,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Compound rewards on every ready planet in one transaction
+    /// Remaining accounts are (planet, planet NFT token account, metadata) triples;
+    /// planets that are not ready yet are skipped
+    pub fn compound_all<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CompoundAll<'info>>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let pool_balance = ctx.accounts.reward_pool.amount;
+        let tier_table = &ctx.accounts.tier_table;
+        let owner = ctx.accounts.authority.key();
+        let clock = Clock::get()?;
+
+        let triples = ctx.remaining_accounts.chunks_exact(3);
+        require!(
+            !ctx.remaining_accounts.is_empty() && triples.remainder().is_empty(),
+            ErrorCode::InvalidRemainingAccounts
+        );
+
+        let mut results = Vec::new();
+
+        for accounts in triples {
+            let mut planet = load_owned_planet(&accounts[0], &accounts[1], &owner)?;
+            let metadata = &accounts[2];
+            // Skip planets that are not ready instead of failing the batch
+            if !is_reward_ready(&planet, config, clock.unix_timestamp) {
+                continue;
+            }
+
+            require_keys_eq!(
+                metadata.key(),
+                Metadata::find_pda(&planet.mint).0,
+                ErrorCode::InvalidRemainingAccounts
+            );
+
+            let previous_level = planet.compound_level;
+            let reward = compound_planet(
+                &mut planet,
+                config,
+                tier_table,
+                &ctx.accounts.emission_schedule,
+                pool_balance,
+                clock.unix_timestamp,
+            )?;
+            if planet.compound_level != previous_level {
+                update_planet_metadata(
+                    &ctx.accounts.token_metadata_program,
+                    metadata,
+                    &ctx.accounts.program_authority,
+                    config.authority_bump,
+                    &planet,
+                    tier_table,
+                )?;
+            }
+            planet.exit(&crate::ID)?;
+
+            emit!(RewardsCompounded {
+                owner,
+                planet: planet.key(),
+                reward,
+                locked_tokens: planet.locked_tokens,
+                compound_level: planet.compound_level,
+                daily_reward: planet.daily_reward,
+                prestige: planet.prestige,
+                timestamp: clock.unix_timestamp,
+            });
+            results.push(CompoundResult {
+                planet: planet.key(),
+                compound_level: planet.compound_level,
+                locked_tokens: planet.locked_tokens,
+                prestige: planet.prestige,
+            });
+        }
+
+        require!(!results.is_empty(), ErrorCode::RewardNotReady);
+
+        msg!("Compounded rewards on {} planets", results.len());
+        emit!(PlanetsCompounded {
+            owner,
+            results,
+        });
         Ok(())
     }
 
+    /// Report a planet's accrued rewards without changing any state
+    /// Intended to be simulated; the result is returned via return data
+    pub fn pending_rewards(ctx: Context<PendingRewards>) -> Result<PendingRewardsView> {
+        let planet = &ctx.accounts.planet_account;
+        let config = &ctx.accounts.config;
+        let clock = Clock::get()?;
+
+        Ok(PendingRewardsView {
+            pending_reward: calculate_pending_reward(
+                planet,
+                config,
+                &ctx.accounts.emission_schedule,
+                clock.unix_timestamp,
+            )?,
+            next_claimable_at: if planet.owed_rewards > 0 {
+                planet.last_claim
+            } else {
+                planet.last_claim + config.reward_interval as i64
+            },
+            accrual_capped_at: planet.last_claim.saturating_add(config.max_accrual_window),
+            accrual_expires_at: match config.accrual_cap_mode {
+                AccrualCapMode::Hold => None,
+                AccrualCapMode::Decay => Some(
+                    planet.last_claim.saturating_add(config.max_accrual_window.saturating_mul(2)),
+                ),
+            },
+            daily_reward: planet.daily_reward,
+            rate_unit: planet.rate_unit,
+            next_level_reward: ctx.accounts.tier_table.tiers
+                .get(planet.compound_level as usize + 1)
+                .map(|tier| get_tier_reward_bps(config, tier)),
+        })
+    }
+
+    /// Report the reward pool's balance against outstanding locked tokens
+    /// Intended to be simulated; the result is returned via return data
+    pub fn pool_solvency(ctx: Context<PoolSolvency>) -> Result<SolvencyView> {
+        let config = &ctx.accounts.config;
+        let pool_balance = ctx.accounts.reward_pool.amount;
+        let liabilities = config.total_locked_tokens;
+
+        Ok(SolvencyView {
+            pool_balance,
+            liabilities,
+            required_reserve: required_reserve(config)?,
+            available_rewards: available_rewards(config, pool_balance)?,
+            reserve_ratio_bps: config.reserve_ratio_bps,
+            solvency_ratio_bps: if liabilities == 0 {
+                u64::MAX
+            } else {
+                math::mul_div(pool_balance, math::BPS_DENOMINATOR, liabilities)
+                    .unwrap_or(u64::MAX)
+            },
+        })
+    }
+
+    /// Preview the emission multiplier and every tier's effective rate at `timestamp`
+    /// Intended to be simulated; the result is returned via return data
+    pub fn preview_emission(ctx: Context<PreviewEmission>, timestamp: i64) -> Result<EmissionPreview> {
+        let (multiplier_bps, next_change) =
+            emission_multiplier_at(&ctx.accounts.emission_schedule, timestamp);
+        let tier_rates = ctx.accounts.tier_table.tiers
+            .iter()
+            .map(|tier| math::apply_bps(get_tier_reward_bps(&ctx.accounts.config, tier) as u64, multiplier_bps))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(EmissionPreview {
+            timestamp,
+            multiplier_bps,
+            next_change,
+            tier_rates,
+        })
+    }
+
     /// Transfer planet NFT to another user (with 5% tax)
+    /// The planet's slot moves from the seller's user account to the buyer's, which must
+    /// be under `max_planets_per_user`. Planets can also move through any marketplace;
+    /// whoever holds the NFT owns the planet, but slots only move with this instruction.
     pub fn transfer_planet(ctx: Context<TransferPlanet>) -> Result<()> {
-        let planet = &mut ctx.accounts.planet_account;
-        let seller = &mut ctx.accounts.seller;
-        let buyer = &mut ctx.accounts.buyer;
+        let planet = &ctx.accounts.planet_account;
         let config = &ctx.accounts.config;
+        let buyer_user = &mut ctx.accounts.buyer_user;
+
+        // Move the planet's slot from the seller to the buyer
+        if !buyer_user.planets.contains(&planet.key()) {
+            require!(
+                buyer_user.planets.len() < config.max_planets_per_user as usize,
+                ErrorCode::MaxPlanetsReached
+            );
+            buyer_user.planets.push(planet.key());
+        }
+        if let Some(seller_user) = ctx.accounts.seller_user.as_mut() {
+            seller_user.planets.retain(|&x| x != planet.key());
+        }
 
         // Calculate transfer tax (5% of locked tokens)
-        let tax = (planet.locked_tokens as f64 * (config.nft_transfer_tax_rate as f64 / 100.0)) as u64;
-        let team_tax = (tax as f64 * (config.team_nft_tax_rate as f64 / config.nft_transfer_tax_rate as f64)) as u64;
-        let reward_tax = tax - team_tax;
+        let tax = math::apply_percent(planet.locked_tokens, config.nft_transfer_tax_rate)?;
+        let team_tax = math::mul_div(
+            tax,
+            config.team_nft_tax_rate as u64,
+            config.nft_transfer_tax_rate as u64,
+        )?;
+        let reward_tax = math::checked_sub(tax, team_tax)?;
         
         // Transfer tax to team wallet
         let cpi_accounts_team = token::Transfer {
@@ -276,19 +733,260 @@ pub mod universe_solana_backend {
             authority: ctx.accounts.authority.to_account_info(),
         };
         let cpi_ctx = CpiContext::new(
-            cpi_program,
+            cpi_program.clone(),
             cpi_accounts_reward,
         );
         token::transfer(cpi_ctx, reward_tax)?;
 
-        // Update planet ownership
-        planet.owner = buyer.key();
-        
-        // Update buyer and seller planet lists
-        buyer.planets.push(planet.key());
-        seller.planets.retain(|&x| x != planet.key());
+        // Move the planet NFT to the buyer, which transfers ownership of the planet
+        let cpi_accounts_nft = token::Transfer {
+            from: ctx.accounts.seller_planet_token_account.to_account_info(),
+            to: ctx.accounts.buyer_planet_token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            cpi_program,
+            cpi_accounts_nft,
+        );
+        token::transfer(cpi_ctx, 1)?;
         
-        msg!("Planet transferred from {:?} to {:?} with tax: {}", seller.key(), buyer.key(), tax);
+        msg!("Planet transferred from {:?} to {:?} with tax: {}", 
+            ctx.accounts.authority.key(), ctx.accounts.buyer_planet_token_account.owner, tax);
+        emit!(PlanetTransferred {
+            planet: planet.key(),
+            from: ctx.accounts.authority.key(),
+            to: ctx.accounts.buyer_planet_token_account.owner,
+            tax,
+            team_tax,
+            reward_tax,
+        });
+        Ok(())
+    }
+    
+    /// Free the signer's slots for planets they no longer hold
+    /// Remaining accounts are (planet, planet NFT token account) pairs. A slot is freed
+    /// when the planet account is closed, or when the token account shows the planet NFT
+    /// held by another wallet (e.g. after a marketplace sale); for closed planets the
+    /// second account is ignored.
+    pub fn release_planet_slots<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleasePlanetSlots<'info>>,
+    ) -> Result<()> {
+        let user = &mut ctx.accounts.user;
+
+        let pairs = ctx.remaining_accounts.chunks_exact(2);
+        require!(
+            !ctx.remaining_accounts.is_empty() && pairs.remainder().is_empty(),
+            ErrorCode::InvalidRemainingAccounts
+        );
+
+        let mut released = Vec::new();
+        for accounts in pairs {
+            let planet_info = &accounts[0];
+            require!(user.planets.contains(planet_info.key), ErrorCode::InvalidRemainingAccounts);
+
+            // A closed planet no longer belongs to anyone; otherwise the NFT must be elsewhere
+            if planet_info.owner == &crate::ID && !planet_info.data_is_empty() {
+                let planet: Account<Planet> = Account::try_from(planet_info)?;
+                let token_account: Account<TokenAccount> = Account::try_from(&accounts[1])?;
+                require!(
+                    token_account.mint == planet.mint
+                        && token_account.amount == 1
+                        && token_account.owner != user.authority,
+                    ErrorCode::PlanetStillHeld
+                );
+            }
+
+            user.planets.retain(|x| x != planet_info.key);
+            released.push(planet_info.key());
+        }
+
+        msg!("Released {} planet slots for user {:?}", released.len(), user.authority);
+        emit!(PlanetSlotsReleased {
+            user: user.key(),
+            planets: released,
+        });
+        Ok(())
+    }
+
+    /// Decommission a planet: burn its NFT, return its locked tokens and close it
+    /// Exits within `early_exit_period` of creation pay `early_exit_penalty_rate`,
+    /// which stays in the reward pool. Banked `owed_rewards` are paid out, rationed
+    /// like claims; rewards accrued since the last claim are forfeited.
+    pub fn decommission_planet(ctx: Context<DecommissionPlanet>) -> Result<()> {
+        let planet = &ctx.accounts.planet_account;
+        let config = &mut ctx.accounts.config;
+        let clock = Clock::get()?;
+
+        // Calculate early exit penalty
+        let penalty = if clock.unix_timestamp - planet.created_at < config.early_exit_period {
+            math::apply_percent(planet.locked_tokens, config.early_exit_penalty_rate)?
+        } else {
+            0
+        };
+        let returned = math::checked_sub(planet.locked_tokens, penalty)?;
+        config.total_locked_tokens = math::checked_sub(config.total_locked_tokens, planet.locked_tokens)?;
+        update_reward_index(config, clock.unix_timestamp)?;
+        config.total_shares = math::checked_sub(config.total_shares, planet.shares)?;
+
+        // Pay banked rewards from what is left above the reserve; an empty pool never blocks the exit
+        let pool_balance = math::checked_sub(ctx.accounts.reward_pool.amount, returned)?;
+        let owed_paid = if planet.owed_rewards > 0 && available_rewards(config, pool_balance)? > 0 {
+            ration_reward(config, pool_balance, planet.owed_rewards, clock.unix_timestamp)?
+        } else {
+            0
+        };
+
+        // Create PDA signer for reward pool
+        let authority_seeds = &[
+            b"authority".as_ref(),
+            &[config.authority_bump],
+        ];
+        let signer = &[&authority_seeds[..]];
+
+        // Return locked tokens and banked rewards from pool to owner
+        let cpi_accounts = token::Transfer {
+            from: ctx.accounts.reward_pool.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.program_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(
+            cpi_program,
+            cpi_accounts,
+            signer,
+        );
+        token::transfer(cpi_ctx, math::checked_add(returned, owed_paid)?)?;
+        if owed_paid > 0 {
+            emit!(RewardsClaimed {
+                owner: ctx.accounts.authority.key(),
+                planet: planet.key(),
+                amount: owed_paid,
+                recipient: ctx.accounts.user_token_account.key(),
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        // Burn the planet NFT, closing its metadata, edition and token account
+        BurnNftCpiBuilder::new(&ctx.accounts.token_metadata_program.to_account_info())
+            .metadata(&ctx.accounts.metadata.to_account_info())
+            .owner(&ctx.accounts.authority.to_account_info())
+            .mint(&ctx.accounts.planet_mint.to_account_info())
+            .token_account(&ctx.accounts.planet_token_account.to_account_info())
+            .master_edition_account(&ctx.accounts.master_edition.to_account_info())
+            .spl_token_program(&ctx.accounts.token_program.to_account_info())
+            .invoke()?;
+
+        // Free the slot if the planet is tracked in the owner's user account
+        if let Some(user) = ctx.accounts.user.as_mut() {
+            user.planets.retain(|&x| x != planet.key());
+        }
+
+        msg!("Planet {} decommissioned. Returned {} tokens with {} penalty", 
+            planet.key(), returned, penalty);
+        emit!(PlanetDecommissioned {
+            owner: ctx.accounts.authority.key(),
+            planet: planet.key(),
+            returned,
+            penalty,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+    
+    /// Withdraw part of a planet's locked tokens, downgrading it per `Config.downgrade_rule`
+    /// and further to the highest tier whose `min_locked_tokens` it still meets.
+    /// The planet must keep at least `planet_creation_cost` locked; use
+    /// decommission_planet to exit fully. The early exit penalty also applies here.
+    /// Rewards earned before the withdrawal are kept at the old balance and rate,
+    /// and can be claimed or compounded right away.
+    pub fn withdraw_from_planet(ctx: Context<WithdrawFromPlanet>, amount: u64) -> Result<()> {
+        let planet = &mut ctx.accounts.planet_account;
+        let config = &mut ctx.accounts.config;
+        let clock = Clock::get()?;
+
+        // Keep the planet backed by at least the creation cost
+        let remaining = math::checked_sub(planet.locked_tokens, amount)?;
+        require!(
+            amount > 0 && remaining >= config.planet_creation_cost,
+            ErrorCode::InvalidWithdrawAmount
+        );
+
+        // Calculate early exit penalty
+        let penalty = if clock.unix_timestamp - planet.created_at < config.early_exit_period {
+            math::apply_percent(amount, config.early_exit_penalty_rate)?
+        } else {
+            0
+        };
+        let returned = math::checked_sub(amount, penalty)?;
+
+        // Keep rewards earned so far before the balance and rate drop
+        bank_planet_rewards(planet, config, &ctx.accounts.emission_schedule, clock.unix_timestamp)?;
+
+        // Downgrade the planet
+        planet.compound_level = match config.downgrade_rule {
+            DowngradeRule::OneLevel => planet.compound_level.saturating_sub(1),
+            DowngradeRule::Proportional => math::mul_div(
+                planet.compound_level as u64,
+                remaining,
+                planet.locked_tokens,
+            )? as u8,
+            DowngradeRule::Reset => 0,
+        };
+        // Keep stepping down until the remaining balance meets the tier minimum
+        while planet.compound_level > 0
+            && remaining < get_tier_for_level(&ctx.accounts.tier_table, planet.compound_level)?.min_locked_tokens
+        {
+            planet.compound_level -= 1;
+        }
+        let tier = get_tier_for_level(&ctx.accounts.tier_table, planet.compound_level)?;
+        planet.compound_count = tier.compounds_required;
+        planet.locked_tokens = remaining;
+        planet.name = tier.name.clone();
+        config.total_locked_tokens = math::checked_sub(config.total_locked_tokens, amount)?;
+        rebase_planet(planet, config, &ctx.accounts.tier_table)?;
+
+        // Create PDA signer for reward pool
+        let authority_seeds = &[
+            b"authority".as_ref(),
+            &[config.authority_bump],
+        ];
+        let signer = &[&authority_seeds[..]];
+
+        // Return withdrawn tokens from pool to owner
+        let cpi_accounts = token::Transfer {
+            from: ctx.accounts.reward_pool.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.program_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(
+            cpi_program,
+            cpi_accounts,
+            signer,
+        );
+        token::transfer(cpi_ctx, returned)?;
+
+        // Update NFT metadata to follow the downgrade
+        update_planet_metadata(
+            &ctx.accounts.token_metadata_program,
+            &ctx.accounts.metadata,
+            &ctx.accounts.program_authority,
+            config.authority_bump,
+            planet,
+            &ctx.accounts.tier_table,
+        )?;
+
+        msg!("Withdrew {} tokens with {} penalty. New locked tokens: {}, New compound level: {}, Planet: {}",
+            returned, penalty, planet.locked_tokens, planet.compound_level, planet.name);
+        emit!(PlanetWithdrawn {
+            owner: ctx.accounts.authority.key(),
+            planet: planet.key(),
+            amount,
+            penalty,
+            locked_tokens: planet.locked_tokens,
+            compound_level: planet.compound_level,
+            timestamp: clock.unix_timestamp,
+        });
         Ok(())
     }
     
@@ -299,18 +997,18 @@ pub mod universe_solana_backend {
         let config = &ctx.accounts.config;
         
         // Calculate tax amounts
-        let total_tax = (amount as f64 * (config.transaction_tax_rate as f64 / 100.0)) as u64;
-        let liquidity_tax = (amount as f64 * (config.liquidity_tax_rate as f64 / 100.0)) as u64;
-        let reward_tax = (amount as f64 * (config.reward_tax_rate as f64 / 100.0)) as u64;
+        let total_tax = math::apply_percent(amount, config.transaction_tax_rate)?;
+        let liquidity_tax = math::apply_percent(amount, config.liquidity_tax_rate)?;
+        let reward_tax = math::apply_percent(amount, config.reward_tax_rate)?;
         
         // Verify tax calculation
         require!(
-            liquidity_tax + reward_tax <= total_tax,
+            math::checked_add(liquidity_tax, reward_tax)? <= total_tax,
             ErrorCode::InvalidTaxCalculation
         );
         
         // Calculate amount after tax
-        let transfer_amount = amount - total_tax;
+        let transfer_amount = math::checked_sub(amount, total_tax)?;
         
         // Transfer liquidity tax
         let cpi_accounts_liquidity = token::Transfer {
@@ -351,10 +1049,18 @@ pub mod universe_solana_backend {
         
         msg!("Transferred {} $UNIV with {} tax ({}+{})", 
             transfer_amount, total_tax, liquidity_tax, reward_tax);
+        emit!(TaxCollected {
+            from: ctx.accounts.from.key(),
+            to: ctx.accounts.to.key(),
+            amount: transfer_amount,
+            total_tax,
+            liquidity_tax,
+            reward_tax,
+        });
         Ok(())
     }
     
-    /// Claim vested tokens (for ecosystem development and treasury)
+    /// Claim vested tokens (for ecosystem development and treasury, admin only)
     pub fn claim_vested_tokens(
         ctx: Context<ClaimVestedTokens>, 
         vesting_type: VestingType
@@ -363,16 +1069,10 @@ pub mod universe_solana_backend {
         let vesting = &mut ctx.accounts.vesting;
         let clock = Clock::get()?;
         
-        // Only admin can call this function
-        require!(
-            ctx.accounts.authority.key() == config.admin,
-            ErrorCode::Unauthorized
-        );
-        
         // Calculate time elapsed since vesting start
         let elapsed = clock.unix_timestamp - config.vesting_start_time;
         
-        // Create PDA signer for reward pool
+        // Create PDA signer for the vesting vault
         let authority_seeds = &[
             b"authority".as_ref(),
             &[config.authority_bump],
@@ -382,9 +1082,11 @@ pub mod universe_solana_backend {
         match vesting_type {
             VestingType::Ecosystem => {
                 // Calculate claimable amount based on linear vesting over 1 year
-                let total_vesting_period = config.ecosystem_vesting_duration;
-                let vesting_progress = elapsed as f64 / total_vesting_period as f64;
-                let claimable_amount = (vesting.ecosystem_amount as f64 * vesting_progress) as u64;
+                let claimable_amount = math::vested_amount(
+                    vesting.ecosystem_amount,
+                    elapsed,
+                    config.ecosystem_vesting_duration,
+                )?;
                 
                 // Calculate amount not yet claimed
                 let remaining_claimable = claimable_amount.saturating_sub(vesting.ecosystem_claimed);
@@ -410,15 +1112,23 @@ pub mod universe_solana_backend {
                 token::transfer(cpi_ctx, remaining_claimable)?;
                 
                 // Update claimed amount
-                vesting.ecosystem_claimed += remaining_claimable;
+                vesting.ecosystem_claimed = math::checked_add(vesting.ecosystem_claimed, remaining_claimable)?;
                 
                 msg!("Claimed {} ecosystem vested tokens", remaining_claimable);
+                emit!(VestedClaimed {
+                    vesting_type: VestingType::Ecosystem,
+                    amount: remaining_claimable,
+                    total_claimed: vesting.ecosystem_claimed,
+                    recipient: ctx.accounts.recipient.key(),
+                });
             },
             VestingType::Treasury => {
                 // Calculate claimable amount based on linear vesting over 1 year
-                let total_vesting_period = config.treasury_vesting_duration;
-                let vesting_progress = elapsed as f64 / total_vesting_period as f64;
-                let claimable_amount = (vesting.treasury_amount as f64 * vesting_progress) as u64;
+                let claimable_amount = math::vested_amount(
+                    vesting.treasury_amount,
+                    elapsed,
+                    config.treasury_vesting_duration,
+                )?;
                 
                 // Calculate amount not yet claimed
                 let remaining_claimable = claimable_amount.saturating_sub(vesting.treasury_claimed);
@@ -444,9 +1154,15 @@ pub mod universe_solana_backend {
                 token::transfer(cpi_ctx, remaining_claimable)?;
                 
                 // Update claimed amount
-                vesting.treasury_claimed += remaining_claimable;
+                vesting.treasury_claimed = math::checked_add(vesting.treasury_claimed, remaining_claimable)?;
                 
                 msg!("Claimed {} treasury vested tokens", remaining_claimable);
+                emit!(VestedClaimed {
+                    vesting_type: VestingType::Treasury,
+                    amount: remaining_claimable,
+                    total_claimed: vesting.treasury_claimed,
+                    recipient: ctx.accounts.recipient.key(),
+                });
             },
         }
         
@@ -480,6 +1196,10 @@ pub mod universe_solana_backend {
         token::burn(cpi_ctx, amount)?;
         
         msg!("Burned {} tokens from burn reserve", amount);
+        emit!(TokensBurned {
+            burn_reserve: ctx.accounts.burn_reserve.key(),
+            amount,
+        });
         Ok(())
     }
     
@@ -503,8 +1223,11 @@ pub mod universe_solana_backend {
         );
         
         // Update config parameters if provided
+        // Planets pick up a new base rate on their next claim or compound
         if let Some(rate) = reward_rate {
+            require!(rate <= 100, ErrorCode::InvalidRewardRate);
             config.reward_rate = rate;
+            config.daily_rate_bps = math::percent_to_bps(rate) as u16;
         }
         
         if let Some(cost) = planet_creation_cost {
@@ -530,54 +1253,338 @@ pub mod universe_solana_backend {
         );
         
         msg!("Config updated");
+        emit_config_updated(config);
         Ok(())
     }
-}
-
-/*** CONTEXT STRUCTS ***/
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(init, payer = admin, space = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 32 + 32 + 32 + 32 + 1 + 1 + 1 + 1 + 1 + 1 + 8 + 8 + 8)]
-    pub config: Account<'info, Config>,
-    
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    
-    /// CHECK: This is the token reward pool
-    #[account(mut)]
-    pub reward_pool: AccountInfo<'info>,
-    
-    /// CHECK: This is the team wallet
-    #[account(mut)]
-    pub team_wallet: AccountInfo<'info>,
-    
-    /// CHECK: This is the marketing wallet
-    #[account(mut)]
-    pub marketing_wallet: AccountInfo<'info>,
-    
-    /// CHECK: This is the liquidity wallet
-    #[account(mut)]
-    pub liquidity_wallet: AccountInfo<'info>,
-    
-    /// CHECK: PDA to be used as authority for reward pool operations
-    #[account(
-        seeds = [b"authority"],
-        bump,
-    )]
-    pub authority: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    /// Replace the tier at `index`, or append a new top tier when `index` equals the tier count (admin only)
+    pub fn set_tier(ctx: Context<UpdateTierTable>, index: u8, tier: Tier) -> Result<()> {
+        let tier_table = &mut ctx.accounts.tier_table;
+        let index = index as usize;
+        
+        // Validate the tier and keep requirements ordered from tier to tier
+        require!(
+            !tier.name.is_empty()
+                && tier.name.len() <= MAX_TIER_NAME_LENGTH
+                && tier.metadata_uri.len() <= MAX_TIER_URI_LENGTH
+                && tier.bonus_bps as u64 <= math::BPS_DENOMINATOR,
+            ErrorCode::InvalidTier
+        );
+        require!(index > 0 || tier.compounds_required == 0, ErrorCode::InvalidTier);
+        if let Some(previous) = index.checked_sub(1).and_then(|i| tier_table.tiers.get(i)) {
+            require!(
+                tier.compounds_required >= previous.compounds_required,
+                ErrorCode::InvalidTier
+            );
+        }
+        if let Some(next) = tier_table.tiers.get(index + 1) {
+            require!(
+                tier.compounds_required <= next.compounds_required,
+                ErrorCode::InvalidTier
+            );
+        }
+        
+        if index < tier_table.tiers.len() {
+            tier_table.tiers[index] = tier.clone();
+        } else {
+            require!(
+                index == tier_table.tiers.len() && index < MAX_TIERS,
+                ErrorCode::InvalidTier
+            );
+            tier_table.tiers.push(tier.clone());
+        }
+        
+        msg!("Tier {} set to {}", index, tier.name);
+        emit!(TierUpdated {
+            index: index as u8,
+            tier,
+        });
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct InitializeUser<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + 32 + 4 + (10 * 32) // Support for max 10 planets
+    /// Replace the emission schedule's curve segments (admin only)
+    /// Segments must be ordered by start time; before the first one planets earn their full rate.
+    /// Epochs must last at least one reward interval, and decaying curves must actually decay,
+    /// so accrual only ever walks a bounded number of epochs.
+    pub fn set_emission_schedule(
+        ctx: Context<UpdateEmissionSchedule>,
+        segments: Vec<EmissionSegment>,
+    ) -> Result<()> {
+        require!(segments.len() <= MAX_EMISSION_SEGMENTS, ErrorCode::InvalidEmissionSchedule);
+        let reward_interval = ctx.accounts.config.reward_interval as i64;
+        
+        for (i, segment) in segments.iter().enumerate() {
+            let decay_bps = match segment.curve {
+                DecayCurve::Flat | DecayCurve::Halving => 0,
+                DecayCurve::Linear { step_bps } => step_bps,
+                DecayCurve::Exponential { rate_bps } => rate_bps,
+            };
+            require!(
+                segment.epoch_duration > 0
+                    && segment.epoch_duration >= reward_interval
+                    && segment.initial_multiplier_bps as u64 <= math::BPS_DENOMINATOR
+                    && decay_bps as u64 <= math::BPS_DENOMINATOR
+                    && !matches!(
+                        segment.curve,
+                        DecayCurve::Linear { step_bps: 0 } | DecayCurve::Exponential { rate_bps: 0 }
+                    ),
+                ErrorCode::InvalidEmissionSchedule
+            );
+            if i > 0 {
+                require!(
+                    segment.start_time > segments[i - 1].start_time,
+                    ErrorCode::InvalidEmissionSchedule
+                );
+            }
+        }
+        
+        ctx.accounts.emission_schedule.segments = segments.clone();
+        
+        msg!("Emission schedule set with {} segments", segments.len());
+        emit!(EmissionScheduleUpdated { segments });
+        Ok(())
+    }
+
+    /// Update planet exit parameters (admin only)
+    pub fn update_exit_config(
+        ctx: Context<UpdateConfig>,
+        early_exit_penalty_rate: Option<u8>,
+        early_exit_period: Option<i64>,
+        downgrade_rule: Option<DowngradeRule>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        if let Some(rate) = early_exit_penalty_rate {
+            require!(rate <= 100, ErrorCode::InvalidExitConfig);
+            config.early_exit_penalty_rate = rate;
+        }
+        
+        if let Some(period) = early_exit_period {
+            require!(period >= 0, ErrorCode::InvalidExitConfig);
+            config.early_exit_period = period;
+        }
+        
+        if let Some(rule) = downgrade_rule {
+            config.downgrade_rule = rule;
+        }
+        
+        msg!("Exit config updated");
+        emit_config_updated(config);
+        Ok(())
+    }
+
+    /// Update the reward pool reserve ratio (admin only)
+    pub fn update_solvency_config(
+        ctx: Context<UpdateConfig>,
+        reserve_ratio_bps: Option<u16>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        if let Some(ratio) = reserve_ratio_bps {
+            require!(ratio as u64 <= math::BPS_DENOMINATOR, ErrorCode::InvalidReserveRatio);
+            config.reserve_ratio_bps = ratio;
+        }
+        
+        msg!("Solvency config updated");
+        emit_config_updated(config);
+        Ok(())
+    }
+
+    /// Update reward accounting mode and accumulator emission rate (admin only)
+    /// Switching modes does not carry over rewards accrued under the previous mode
+    pub fn update_emission_config(
+        ctx: Context<UpdateConfig>,
+        reward_mode: Option<RewardMode>,
+        emission_rate: Option<u64>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        // Settle emissions at the old rate before changing it
+        update_reward_index(config, Clock::get()?.unix_timestamp)?;
+        
+        if let Some(mode) = reward_mode {
+            config.reward_mode = mode;
+        }
+        
+        if let Some(rate) = emission_rate {
+            config.emission_rate = rate;
+        }
+        
+        msg!("Emission config updated");
+        emit_config_updated(config);
+        Ok(())
+    }
+
+    /// Update how unclaimed rewards accrue: for how long, what happens after,
+    /// and whether partial intervals count (admin only)
+    /// The window must be between one reward interval and `MAX_ACCRUAL_WINDOW`.
+    pub fn update_accrual_config(
+        ctx: Context<UpdateConfig>,
+        max_accrual_window: Option<i64>,
+        accrual_cap_mode: Option<AccrualCapMode>,
+        interval_mode: Option<IntervalMode>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        if let Some(window) = max_accrual_window {
+            require!(
+                (config.reward_interval as i64..=MAX_ACCRUAL_WINDOW).contains(&window),
+                ErrorCode::InvalidAccrualConfig
+            );
+            config.max_accrual_window = window;
+        }
+        
+        if let Some(mode) = accrual_cap_mode {
+            config.accrual_cap_mode = mode;
+        }
+        
+        if let Some(mode) = interval_mode {
+            config.interval_mode = mode;
+        }
+        
+        msg!("Accrual config updated");
+        emit_config_updated(config);
+        Ok(())
+    }
+
+    /// Update the unit planet reward rates are expressed in and the base rate
+    /// for each unit, in basis points (admin only)
+    /// Existing planets keep their old unit until their next claim or migrate_planet_rate_units
+    pub fn update_rate_config(
+        ctx: Context<UpdateConfig>,
+        rate_unit: Option<RateUnit>,
+        interval_rate_bps: Option<u16>,
+        daily_rate_bps: Option<u16>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        if let Some(unit) = rate_unit {
+            config.rate_unit = unit;
+        }
+        
+        if let Some(bps) = interval_rate_bps {
+            require!(bps as u64 <= math::BPS_DENOMINATOR, ErrorCode::InvalidRewardRate);
+            config.interval_rate_bps = bps;
+        }
+        
+        if let Some(bps) = daily_rate_bps {
+            require!(bps as u64 <= math::BPS_DENOMINATOR, ErrorCode::InvalidRewardRate);
+            config.daily_rate_bps = bps;
+        }
+        
+        msg!("Rate config updated");
+        emit_config_updated(config);
+        Ok(())
+    }
+
+    /// Re-express existing planets in the configured rate unit (admin only)
+    /// Each planet's rate is recomputed from the base rate for that unit, so
+    /// switching units keeps the daily payout instead of relabelling the number.
+    /// Remaining accounts are writable planets; planets already in the configured
+    /// unit are skipped. Rewards earned under the old unit are kept in `owed_rewards`
+    /// and paid with the next claim or compound; the new unit applies from now on.
+    pub fn migrate_planet_rate_units<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigratePlanetRateUnits<'info>>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let tier_table = &ctx.accounts.tier_table;
+        let emission_schedule = &ctx.accounts.emission_schedule;
+        let clock = Clock::get()?;
+        
+        require!(!ctx.remaining_accounts.is_empty(), ErrorCode::InvalidRemainingAccounts);
+        update_reward_index(config, clock.unix_timestamp)?;
+        
+        let mut planets = Vec::new();
+        for planet_info in ctx.remaining_accounts {
+            require!(planet_info.is_writable, ErrorCode::InvalidRemainingAccounts);
+            let mut planet: Account<Planet> = Account::try_from(planet_info)?;
+            if planet.rate_unit == config.rate_unit {
+                continue;
+            }
+            
+            bank_planet_rewards(&mut planet, config, emission_schedule, clock.unix_timestamp)?;
+            rebase_planet(&mut planet, config, tier_table)?;
+            planet.exit(&crate::ID)?;
+            planets.push(planet.key());
+        }
+        
+        msg!("Migrated {} planets to {:?} rates", planets.len(), config.rate_unit);
+        emit!(PlanetRatesMigrated {
+            rate_unit: config.rate_unit,
+            planets,
+        });
+        Ok(())
+    }
+}
+
+/*** CONTEXT STRUCTS ***/
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 32 + 32 + 32 + 32 + 1 + 1 + 1 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 1 + 8 + 2 + 1 + 8 + 16 + 8 + 8 + 8 + 1 + 1 + 1 + 2 + 2,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::UniverseSolanaBackend>,
+    
+    /// Program data account; its upgrade authority must be the admin
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub token_mint: Account<'info, Mint>,
+    
+    /// Reward pool vault owned by the authority PDA
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"reward_pool"],
+        bump,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+    
+    /// Team wallet token account that receives the team share of NFT transfer taxes
+    #[account(token::mint = token_mint)]
+    pub team_wallet: Box<Account<'info, TokenAccount>>,
+    
+    /// Marketing wallet token account
+    #[account(token::mint = token_mint)]
+    pub marketing_wallet: Box<Account<'info, TokenAccount>>,
+    
+    /// Liquidity wallet token account that receives the liquidity tax
+    #[account(token::mint = token_mint)]
+    pub liquidity_wallet: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: PDA to be used as authority for reward pool operations
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    pub authority: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeUser<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 4 + (10 * 32) + 1, // Support for max 10 planets
+        seeds = [b"user", authority.key().as_ref()],
+        bump,
     )]
     pub user: Account<'info, User>,
     
@@ -588,47 +1595,230 @@ pub struct InitializeUser<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeTierTable<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 4 + MAX_TIERS * TIER_SPACE,
+        seeds = [b"tier_table"],
+        bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTierTable<'info> {
+    #[account(
+        mut,
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeEmissionSchedule<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 1 + 4 + MAX_EMISSION_SEGMENTS * EMISSION_SEGMENT_SPACE,
+        seeds = [b"emission_schedule"],
+        bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateEmissionSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct SetupVesting<'info> {
     #[account(init, payer = authority, space = 8 + 8 + 8 + 8 + 8 + 8 + 8)]
     pub vesting: Account<'info, Vesting>,
     
+    /// Vault holding the vested allocations, kept apart from the reward pool
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"vesting_vault"],
+        bump,
+        token::mint = token_mint,
+        token::authority = program_authority,
+    )]
+    pub vesting_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = config.token_mint @ ErrorCode::WrongMint)]
+    pub token_mint: Account<'info, Mint>,
+    
+    /// CHECK: PDA that owns the vesting vault
+    #[account(
+        seeds = [b"authority"],
+        bump = config.authority_bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
     #[account(
+        seeds = [b"config"],
+        bump = config.bump,
         constraint = config.admin == authority.key()
     )]
     pub config: Account<'info, Config>,
     
     pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
 pub struct CreatePlanet<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
     pub user: Account<'info, User>,
     
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 1 + 1 + 8 + 8 + 64 + 32 // Add extra space for name and planet_id
+        space = 8 + 1 + 2 + 8 + 8 + 64 + 8 + 32 + 1 + 8 + 4 + 4 + 8 + 16 + 1 + 8, // Add extra space for name, planet_id, mint, bump, created_at, compound_count, prestige, shares, reward_debt, rate_unit and owed_rewards
+        seeds = [b"planet", config.planet_count.to_le_bytes().as_ref()],
+        bump,
     )]
-    pub planet_account: Account<'info, Planet>,
+    pub planet_account: Box<Account<'info, Planet>>,
     
-    #[account(mut)]
-    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = token_account.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = token_account.owner == authority.key() @ ErrorCode::WrongTokenOwner,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
     
-    #[account(mut)]
-    pub reward_pool: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
+    pub reward_pool: Box<Account<'info, TokenAccount>>,
     
-    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Box<Account<'info, TierTable>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = program_authority,
+        mint::freeze_authority = program_authority,
+    )]
+    pub planet_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = planet_mint,
+        associated_token::authority = authority,
+    )]
+    pub planet_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Metaplex metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), planet_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: AccountInfo<'info>,
+    
+    /// CHECK: Metaplex master edition PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), planet_mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub master_edition: AccountInfo<'info>,
+    
+    /// CHECK: PDA that acts as mint and update authority for planet NFTs
+    #[account(
+        seeds = [b"authority"],
+        bump = config.authority_bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+    
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -636,22 +1826,98 @@ pub struct CreatePlanet<'info> {
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
     #[account(mut)]
-    pub user: Account<'info, User>,
+    pub planet_account: Account<'info, Planet>,
+    
+    /// Planet NFT token account proving the signer owns the planet
+    #[account(
+        constraint = planet_token_account.mint == planet_account.mint @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.owner == owner.key() @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.amount == 1 @ ErrorCode::NotPlanetOwner,
+    )]
+    pub planet_token_account: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
     
     #[account(
         mut,
-        constraint = planet_account.owner == user.key()
+        constraint = user_token_account.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = user_token_account.owner == owner.key() @ ErrorCode::WrongTokenOwner,
     )]
-    pub planet_account: Account<'info, Planet>,
+    pub user_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// CHECK: This is the PDA that acts as the authority for the reward pool
+    #[account(
+        seeds = [b"authority"],
+        bump = config.authority_bump,
+    )]
+    pub authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAllRewards<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = user_token_account.owner == owner.key() @ ErrorCode::WrongTokenOwner,
+    )]
     pub user_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
     pub reward_pool: Account<'info, TokenAccount>,
     
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
     pub config: Account<'info, Config>,
     
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
     /// CHECK: This is the PDA that acts as the authority for the reward pool
     #[account(
         seeds = [b"authority"],
@@ -665,43 +1931,399 @@ pub struct ClaimRewards<'info> {
 #[derive(Accounts)]
 pub struct CompoundRewards<'info> {
     #[account(mut)]
-    pub user: Account<'info, User>,
+    pub planet_account: Account<'info, Planet>,
     
+    /// Planet NFT token account proving the signer owns the planet
     #[account(
-        mut,
-        constraint = planet_account.owner == user.key()
+        constraint = planet_token_account.mint == planet_account.mint @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.owner == authority.key() @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.amount == 1 @ ErrorCode::NotPlanetOwner,
     )]
-    pub planet_account: Account<'info, Planet>,
+    pub planet_token_account: Account<'info, TokenAccount>,
     
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
     pub config: Account<'info, Config>,
     
+    #[account(
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// CHECK: Metaplex metadata PDA of the planet NFT
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), planet_account.mint.as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: AccountInfo<'info>,
+    
+    /// CHECK: PDA that acts as update authority for planet NFTs
+    #[account(
+        seeds = [b"authority"],
+        bump = config.authority_bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
+    
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct TransferPlanet<'info> {
+pub struct CompoundAll<'info> {
     #[account(
         mut,
-        constraint = planet_account.owner == seller.key()
+        seeds = [b"config"],
+        bump = config.bump,
     )]
-    pub planet_account: Account<'info, Planet>,
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// CHECK: PDA that acts as update authority for planet NFTs
+    #[account(
+        seeds = [b"authority"],
+        bump = config.authority_bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
     
     #[account(mut)]
-    pub seller: Account<'info, User>,
+    pub authority: Signer<'info>,
+    
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PendingRewards<'info> {
+    pub planet_account: Account<'info, Planet>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+#[derive(Accounts)]
+pub struct PoolSolvency<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct PreviewEmission<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+#[derive(Accounts)]
+pub struct ReleasePlanetSlots<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub user: Account<'info, User>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DecommissionPlanet<'info> {
+    /// Signer's user account, if any; the planet is removed from its list
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub user: Option<Account<'info, User>>,
+    
+    #[account(
+        mut,
+        close = authority,
+    )]
+    pub planet_account: Box<Account<'info, Planet>>,
+    
+    /// Planet NFT token account proving the signer owns the planet
+    #[account(
+        mut,
+        constraint = planet_token_account.mint == planet_account.mint @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.owner == authority.key() @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.amount == 1 @ ErrorCode::NotPlanetOwner,
+    )]
+    pub planet_token_account: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        address = planet_account.mint,
+    )]
+    pub planet_mint: Box<Account<'info, Mint>>,
+    
+    /// CHECK: Metaplex metadata PDA of the planet NFT
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), planet_account.mint.as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: AccountInfo<'info>,
+    
+    /// CHECK: Metaplex master edition PDA of the planet NFT
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), planet_account.mint.as_ref(), b"edition"],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub master_edition: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = user_token_account.owner == authority.key() @ ErrorCode::WrongTokenOwner,
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
+    pub reward_pool: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    /// CHECK: This is the PDA that acts as the authority for the reward pool
+    #[account(
+        seeds = [b"authority"],
+        bump = config.authority_bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
     
     #[account(mut)]
-    pub buyer: Account<'info, User>,
+    pub authority: Signer<'info>,
+    
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
     
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromPlanet<'info> {
     #[account(mut)]
+    pub planet_account: Box<Account<'info, Planet>>,
+    
+    /// Planet NFT token account proving the signer owns the planet
+    #[account(
+        constraint = planet_token_account.mint == planet_account.mint @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.owner == authority.key() @ ErrorCode::NotPlanetOwner,
+        constraint = planet_token_account.amount == 1 @ ErrorCode::NotPlanetOwner,
+    )]
+    pub planet_token_account: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Box<Account<'info, TierTable>>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+    
+    /// CHECK: Metaplex metadata PDA of the planet NFT
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), planet_account.mint.as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = user_token_account.owner == authority.key() @ ErrorCode::WrongTokenOwner,
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
+    pub reward_pool: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    /// CHECK: PDA that acts as reward pool and planet NFT update authority
+    #[account(
+        seeds = [b"authority"],
+        bump = config.authority_bump,
+    )]
+    pub program_authority: AccountInfo<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct TransferPlanet<'info> {
+    pub planet_account: Account<'info, Planet>,
+    
+    /// Seller's planet NFT token account
+    #[account(
+        mut,
+        constraint = seller_planet_token_account.mint == planet_account.mint @ ErrorCode::NotPlanetOwner,
+        constraint = seller_planet_token_account.owner == authority.key() @ ErrorCode::NotPlanetOwner,
+        constraint = seller_planet_token_account.amount == 1 @ ErrorCode::NotPlanetOwner,
+    )]
+    pub seller_planet_token_account: Account<'info, TokenAccount>,
+    
+    /// Buyer's token account that receives the planet NFT
+    #[account(
+        mut,
+        constraint = buyer_planet_token_account.mint == planet_account.mint @ ErrorCode::NotPlanetOwner,
+    )]
+    pub buyer_planet_token_account: Account<'info, TokenAccount>,
+    
+    /// Seller's user account, if any; the planet's slot is freed
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = seller_user.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub seller_user: Option<Box<Account<'info, User>>>,
+    
+    /// Buyer's user account, which takes over the planet's slot
+    #[account(
+        mut,
+        seeds = [b"user", buyer_planet_token_account.owner.as_ref()],
+        bump = buyer_user.bump,
+    )]
+    pub buyer_user: Box<Account<'info, User>>,
+    
+    #[account(
+        mut,
+        constraint = seller_token_account.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = seller_token_account.owner == authority.key() @ ErrorCode::WrongTokenOwner,
+    )]
     pub seller_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
     pub reward_pool: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        address = config.team_wallet @ ErrorCode::InvalidTaxWallet,
+        token::mint = config.token_mint,
+    )]
     pub team_wallet: Account<'info, TokenAccount>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
     pub config: Account<'info, Config>,
     
     #[account(mut)]
@@ -712,18 +2334,37 @@ pub struct TransferPlanet<'info> {
 
 #[derive(Accounts)]
 pub struct TransferWithTax<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = from.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = from.owner == authority.key() @ ErrorCode::WrongTokenOwner,
+    )]
     pub from: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = to.mint == config.token_mint @ ErrorCode::WrongMint,
+    )]
     pub to: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        address = config.reward_pool @ ErrorCode::InvalidRewardPool,
+        token::mint = config.token_mint,
+    )]
     pub reward_pool: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        address = config.liquidity_wallet @ ErrorCode::InvalidTaxWallet,
+        token::mint = config.token_mint,
+    )]
     pub liquidity_wallet: Account<'info, TokenAccount>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
     pub config: Account<'info, Config>,
     
     #[account(mut)]
@@ -737,12 +2378,28 @@ pub struct ClaimVestedTokens<'info> {
     #[account(mut)]
     pub vesting: Account<'info, Vesting>,
     
-    #[account(mut)]
+    /// Dedicated vesting vault; the reward pool can never be drawn from here
+    #[account(
+        mut,
+        seeds = [b"vesting_vault"],
+        bump,
+        token::mint = config.token_mint,
+    )]
     pub token_vault: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = recipient.mint == config.token_mint @ ErrorCode::WrongMint,
+        constraint = recipient.owner == signer.key() @ ErrorCode::WrongTokenOwner,
+    )]
     pub recipient: Account<'info, TokenAccount>,
     
+    /// Only the admin can claim vested tokens
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == signer.key() @ ErrorCode::Unauthorized
+    )]
     pub config: Account<'info, Config>,
     
     /// CHECK: This is the PDA that acts as the authority for the token vault
@@ -760,10 +2417,16 @@ pub struct ClaimVestedTokens<'info> {
 
 #[derive(Accounts)]
 pub struct BurnTokens<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        address = config.token_mint @ ErrorCode::WrongMint,
+    )]
     pub token_mint: Account<'info, Mint>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = burn_reserve.mint == config.token_mint @ ErrorCode::WrongMint,
+    )]
     pub burn_reserve: Account<'info, TokenAccount>,
     
     #[account(
@@ -771,14 +2434,47 @@ pub struct BurnTokens<'info> {
     )]
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
     pub config: Account<'info, Config>,
     
     pub token_program: Program<'info, Token>,
 }
 
+#[derive(Accounts)]
+pub struct MigratePlanetRateUnits<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [b"tier_table"],
+        bump = tier_table.bump,
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    
+    #[account(
+        seeds = [b"emission_schedule"],
+        bump = emission_schedule.bump,
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
     pub config: Account<'info, Config>,
     
     #[account(
@@ -795,6 +2491,7 @@ pub struct Config {
     pub total_supply: u64,
     pub planet_creation_cost: u64,
     pub max_planets_per_user: u8,
+    /// Whole-percent daily base rate; setting it also sets `daily_rate_bps`
     pub reward_rate: u8,
     pub reward_interval: u32,
     pub token_mint: Pubkey,
@@ -813,6 +2510,33 @@ pub struct Config {
     pub vesting_start_time: i64,
     pub ecosystem_vesting_duration: i64,
     pub treasury_vesting_duration: i64,
+    pub planet_count: u64,
+    pub bump: u8,
+    pub early_exit_penalty_rate: u8,
+    pub early_exit_period: i64,
+    pub downgrade_rule: DowngradeRule,
+    /// Sum of locked tokens across all planets, owed back to planet owners
+    pub total_locked_tokens: u64,
+    /// Share of `total_locked_tokens` the reward pool keeps in reserve, in basis points
+    pub reserve_ratio_bps: u16,
+    pub reward_mode: RewardMode,
+    /// Tokens emitted per second across all planets in accumulator mode
+    pub emission_rate: u64,
+    /// Global rewards per weighted share, scaled by ACC_PRECISION
+    pub acc_reward_per_share: u128,
+    /// Sum of weighted shares across all planets
+    pub total_shares: u64,
+    pub last_reward_time: i64,
+    /// Seconds after a claim beyond which per-planet rewards stop growing
+    pub max_accrual_window: i64,
+    pub accrual_cap_mode: AccrualCapMode,
+    pub interval_mode: IntervalMode,
+    /// Period that planet reward rates are expressed over
+    pub rate_unit: RateUnit,
+    /// Base planet reward rate in basis points per reward interval, used with `RateUnit::PerInterval`
+    pub interval_rate_bps: u16,
+    /// Base planet reward rate in basis points per day, used with `RateUnit::PerDay`
+    pub daily_rate_bps: u16,
 }
 
 #[account]
@@ -820,18 +2544,73 @@ pub struct Config {
 pub struct User {
     pub authority: Pubkey,
     pub planets: Vec<Pubkey>,
+    pub bump: u8,
 }
 
 #[account]
 #[derive(Debug)]
 pub struct Planet {
-    pub owner: Pubkey,
     pub compound_level: u8,
-    pub daily_reward: u8,
+    /// Reward rate of the planet's tier in basis points per `rate_unit`
+    pub daily_reward: u16,
     pub last_claim: i64,
     pub locked_tokens: u64,
     pub name: String,
-    pub planet_id: Pubkey,
+    pub planet_id: u64,
+    pub mint: Pubkey,
+    pub bump: u8,
+    pub created_at: i64,
+    pub compound_count: u32,
+    /// Compounds made while already at the top tier; cosmetic only
+    pub prestige: u32,
+    /// Locked tokens weighted by the tier reward rate
+    pub shares: u64,
+    /// Accumulator rewards already settled for `shares`
+    pub reward_debt: u128,
+    /// Unit `daily_reward` is expressed in; follows Config.rate_unit once rebased
+    pub rate_unit: RateUnit,
+    /// Rewards earned before the planet's balance or rate last changed, paid with the next claim
+    pub owed_rewards: u64,
+}
+
+/// Admin-editable planet tiers, indexed by compound level
+#[account]
+#[derive(Debug)]
+pub struct TierTable {
+    pub bump: u8,
+    pub tiers: Vec<Tier>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Tier {
+    pub name: String,
+    /// Rate added to the base rate, in basis points per day; scaled to one
+    /// reward interval when rates are per interval
+    pub bonus_bps: u16,
+    /// Total compounds a planet needs to reach this tier
+    pub compounds_required: u32,
+    /// Locked tokens a planet needs to reach this tier
+    pub min_locked_tokens: u64,
+    pub metadata_uri: String,
+}
+
+/// Admin-defined curve segments that scale every planet's reward rate over time
+#[account]
+#[derive(Debug)]
+pub struct EmissionSchedule {
+    pub bump: u8,
+    pub segments: Vec<EmissionSegment>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EmissionSegment {
+    /// Unix timestamp the segment takes effect
+    pub start_time: i64,
+    /// Length of each epoch in seconds; the curve steps once per epoch
+    pub epoch_duration: i64,
+    /// Multiplier during the segment's first epoch, in basis points
+    pub initial_multiplier_bps: u16,
+    pub curve: DecayCurve,
 }
 
 #[account]
@@ -845,12 +2624,283 @@ pub struct Vesting {
     pub last_claim_time: i64,
 }
 
+/// Return value of the pending_rewards view
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PendingRewardsView {
+    pub pending_reward: u64,
+    pub next_claimable_at: i64,
+    /// When per-planet rewards stop growing if left unclaimed
+    pub accrual_capped_at: i64,
+    /// When unclaimed per-planet rewards have fully decayed, in decay mode
+    pub accrual_expires_at: Option<i64>,
+    pub daily_reward: u16,
+    pub rate_unit: RateUnit,
+    /// Reward rate of the next tier, if the planet can still evolve
+    pub next_level_reward: Option<u16>,
+}
+
+/// Reward pool health returned by pool_solvency
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SolvencyView {
+    pub pool_balance: u64,
+    /// Locked tokens owed back to planet owners
+    pub liabilities: u64,
+    pub required_reserve: u64,
+    /// Pool balance above the required reserve, available for rewards
+    pub available_rewards: u64,
+    pub reserve_ratio_bps: u16,
+    /// Pool balance as a share of liabilities, in basis points (u64::MAX with no liabilities)
+    pub solvency_ratio_bps: u64,
+}
+
+/// Emission schedule state at a given time, returned by preview_emission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EmissionPreview {
+    pub timestamp: i64,
+    pub multiplier_bps: u64,
+    /// When the multiplier next changes, if it ever does
+    pub next_change: Option<i64>,
+    /// Effective reward rate of each tier in basis points per `Config.rate_unit`
+    pub tier_rates: Vec<u64>,
+}
+
+/// Outcome of compounding a single planet in compound_all
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompoundResult {
+    pub planet: Pubkey,
+    pub compound_level: u8,
+    pub locked_tokens: u64,
+    pub prestige: u32,
+}
+
+/// How partial withdrawals downgrade a planet
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DowngradeRule {
+    /// Drop one compound level per withdrawal
+    OneLevel,
+    /// Keep the share of levels matching the share of tokens left locked
+    Proportional,
+    /// Reset the planet back to Earth
+    Reset,
+}
+
+/// How an emission segment's multiplier decays from one epoch to the next
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecayCurve {
+    /// Keep the initial multiplier for the whole segment
+    Flat,
+    /// Halve the multiplier every epoch
+    Halving,
+    /// Subtract `step_bps` from the multiplier every epoch
+    Linear { step_bps: u16 },
+    /// Reduce the multiplier by `rate_bps` of itself every epoch
+    Exponential { rate_bps: u16 },
+}
+
+/// What happens to unclaimed per-planet rewards once the accrual window has passed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccrualCapMode {
+    /// Rewards stay at their capped amount until claimed
+    Hold,
+    /// Rewards shrink back to zero over one more accrual window
+    Decay,
+}
+
+/// Whether per-planet rewards are paid for partial reward intervals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalMode {
+    /// Pay for every elapsed second
+    Continuous,
+    /// Pay only for complete reward intervals
+    WholeIntervals,
+}
+
+/// Period a planet reward rate is expressed over
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateUnit {
+    /// Basis points per reward interval
+    PerInterval,
+    /// Basis points per day, however many reward intervals that spans
+    PerDay,
+}
+
+/// How planet rewards are accounted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RewardMode {
+    /// Each planet earns its tier rate on its locked tokens
+    PerPlanetRate,
+    /// A global emission rate is split across planets by tier-weighted shares
+    Accumulator,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub enum VestingType {
     Ecosystem,
     Treasury,
 }
 
+/*** EVENTS ***/
+
+#[event]
+pub struct UserInitialized {
+    pub authority: Pubkey,
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct VestingSetUp {
+    pub vesting: Pubkey,
+    pub ecosystem_amount: u64,
+    pub treasury_amount: u64,
+    pub burn_reserve_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlanetCreated {
+    pub owner: Pubkey,
+    pub planet: Pubkey,
+    pub planet_id: u64,
+    pub mint: Pubkey,
+    pub locked_tokens: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub owner: Pubkey,
+    pub planet: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsCompounded {
+    pub owner: Pubkey,
+    pub planet: Pubkey,
+    pub reward: u64,
+    pub locked_tokens: u64,
+    pub compound_level: u8,
+    pub daily_reward: u16,
+    pub prestige: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlanetDecommissioned {
+    pub owner: Pubkey,
+    pub planet: Pubkey,
+    pub returned: u64,
+    pub penalty: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlanetWithdrawn {
+    pub owner: Pubkey,
+    pub planet: Pubkey,
+    pub amount: u64,
+    pub penalty: u64,
+    pub locked_tokens: u64,
+    pub compound_level: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlanetTransferred {
+    pub planet: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub tax: u64,
+    pub team_tax: u64,
+    pub reward_tax: u64,
+}
+
+#[event]
+pub struct TaxCollected {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub total_tax: u64,
+    pub liquidity_tax: u64,
+    pub reward_tax: u64,
+}
+
+#[event]
+pub struct VestedClaimed {
+    pub vesting_type: VestingType,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct TokensBurned {
+    pub burn_reserve: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub reward_rate: u8,
+    pub planet_creation_cost: u64,
+    pub transaction_tax_rate: u8,
+    pub liquidity_tax_rate: u8,
+    pub reward_tax_rate: u8,
+    pub early_exit_penalty_rate: u8,
+    pub early_exit_period: i64,
+    pub downgrade_rule: DowngradeRule,
+    pub reserve_ratio_bps: u16,
+    pub reward_mode: RewardMode,
+    pub emission_rate: u64,
+    pub max_accrual_window: i64,
+    pub accrual_cap_mode: AccrualCapMode,
+    pub interval_mode: IntervalMode,
+    pub rate_unit: RateUnit,
+    pub interval_rate_bps: u16,
+    pub daily_rate_bps: u16,
+}
+
+#[event]
+pub struct PoolLow {
+    pub pool_balance: u64,
+    pub liabilities: u64,
+    pub required_reserve: u64,
+    pub requested: u64,
+    pub paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionScheduleUpdated {
+    pub segments: Vec<EmissionSegment>,
+}
+
+#[event]
+pub struct PlanetSlotsReleased {
+    pub user: Pubkey,
+    pub planets: Vec<Pubkey>,
+}
+
+#[event]
+pub struct PlanetRatesMigrated {
+    pub rate_unit: RateUnit,
+    pub planets: Vec<Pubkey>,
+}
+
+#[event]
+pub struct TierUpdated {
+    pub index: u8,
+    pub tier: Tier,
+}
+
+#[event]
+pub struct PlanetsCompounded {
+    pub owner: Pubkey,
+    pub results: Vec<CompoundResult>,
+}
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("Insufficient funds to create a planet.")]
@@ -869,42 +2919,475 @@ pub enum ErrorCode {
     InvalidTaxRates,
     #[msg("No vested tokens available to claim.")]
     NoVestedTokens,
+    #[msg("Signer does not hold the planet NFT.")]
+    NotPlanetOwner,
+    #[msg("Arithmetic overflow.")]
+    MathOverflow,
+    #[msg("Division by zero.")]
+    DivisionByZero,
+    #[msg("Remaining accounts do not match the expected planet account layout.")]
+    InvalidRemainingAccounts,
+    #[msg("Reward pool does not match the configured vault.")]
+    InvalidRewardPool,
+    #[msg("Token account mint does not match the $UNIV mint.")]
+    WrongMint,
+    #[msg("Token account is not owned by the signer.")]
+    WrongTokenOwner,
+    #[msg("Invalid planet exit configuration.")]
+    InvalidExitConfig,
+    #[msg("Withdrawal must leave at least the planet creation cost locked.")]
+    InvalidWithdrawAmount,
+    #[msg("Invalid planet tier.")]
+    InvalidTier,
+    #[msg("Reserve ratio cannot exceed 10,000 basis points.")]
+    InvalidReserveRatio,
+    #[msg("Reward pool has no funds available above its reserve.")]
+    RewardPoolDepleted,
+    #[msg("Invalid emission schedule.")]
+    InvalidEmissionSchedule,
+    #[msg("Reward rate cannot exceed 100%.")]
+    InvalidRewardRate,
+    #[msg("Accrual window must be between one reward interval and 30 days.")]
+    InvalidAccrualConfig,
+    #[msg("Tax wallet does not match the configured wallet.")]
+    InvalidTaxWallet,
+    #[msg("Planet NFT is still held by this user.")]
+    PlanetStillHeld,
 }
 
 /*** UTILITY FUNCTIONS ***/
 
-fn get_reward_for_level(compound_level: u8) -> Result<u8> {
-    match compound_level {
-        0 => Ok(4),  // Earth - 4%
-        1 => Ok(5),  // Moon - 5%
-        2 => Ok(6),  // Mercury - 6%
-        3 => Ok(7),  // Venus - 7%
-        4 => Ok(8),  // Mars - 8%
-        5 => Ok(9),  // Jupiter - 9%
-        6 => Ok(10), // Saturn - 10%
-        7 => Ok(11), // Uranus - 11%
-        8 => Ok(12), // Neptune - 12%
-        10 => Ok(14), // Sun - 14%
-        _ => Err(ErrorCode::InvalidCompoundLevel.into()),
-    }
-}
-
-fn get_planet_name_for_level(compound_level: u8) -> String {
-    match compound_level {
-        0 => "Earth".to_string(),
-        1 => "Moon".to_string(),
-        2 => "Mercury".to_string(),
-        3 => "Venus".to_string(),
-        4 => "Mars".to_string(),
-        5 => "Jupiter".to_string(),
-        6 => "Saturn".to_string(),
-        7 => "Uranus".to_string(),
-        8 => "Neptune".to_string(),
-        10 => "Sun".to_string(),
-        _ => "Unknown".to_string(),
+/// Emit a snapshot of the admin-editable config fields
+fn emit_config_updated(config: &Config) {
+    emit!(ConfigUpdated {
+        reward_rate: config.reward_rate,
+        planet_creation_cost: config.planet_creation_cost,
+        transaction_tax_rate: config.transaction_tax_rate,
+        liquidity_tax_rate: config.liquidity_tax_rate,
+        reward_tax_rate: config.reward_tax_rate,
+        early_exit_penalty_rate: config.early_exit_penalty_rate,
+        early_exit_period: config.early_exit_period,
+        downgrade_rule: config.downgrade_rule,
+        reserve_ratio_bps: config.reserve_ratio_bps,
+        reward_mode: config.reward_mode,
+        emission_rate: config.emission_rate,
+        max_accrual_window: config.max_accrual_window,
+        accrual_cap_mode: config.accrual_cap_mode,
+        interval_mode: config.interval_mode,
+        rate_unit: config.rate_unit,
+        interval_rate_bps: config.interval_rate_bps,
+        daily_rate_bps: config.daily_rate_bps,
+    });
+}
+
+/// Load a writable planet passed as a remaining account and check that
+/// `owner` holds its NFT in `token_account`
+fn load_owned_planet<'info>(
+    planet_info: &'info AccountInfo<'info>,
+    token_info: &'info AccountInfo<'info>,
+    owner: &Pubkey,
+) -> Result<Account<'info, Planet>> {
+    require!(planet_info.is_writable, ErrorCode::InvalidRemainingAccounts);
+
+    let planet: Account<Planet> = Account::try_from(planet_info)?;
+    let token_account: Account<TokenAccount> = Account::try_from(token_info)?;
+
+    require!(
+        token_account.mint == planet.mint
+            && token_account.owner == *owner
+            && token_account.amount == 1,
+        ErrorCode::NotPlanetOwner
+    );
+
+    Ok(planet)
+}
+
+/// Add a planet's pending rewards to its locked tokens and advance it through
+/// every tier whose requirements it now meets; planets stop at the top tier
+fn compound_planet(
+    planet: &mut Planet,
+    config: &mut Config,
+    tier_table: &TierTable,
+    emission_schedule: &EmissionSchedule,
+    pool_balance: u64,
+    now: i64,
+) -> Result<u64> {
+    update_reward_index(config, now)?;
+    let pending = calculate_pending_reward(planet, config, emission_schedule, now)?;
+    let reward = ration_reward(config, pool_balance, pending, now)?;
+    settle_planet_rewards(planet, config)?;
+    
+    // Add rewards to locked tokens
+    planet.locked_tokens = math::checked_add(planet.locked_tokens, reward)?;
+    config.total_locked_tokens = math::checked_add(config.total_locked_tokens, reward)?;
+    planet.compound_count = planet.compound_count.saturating_add(1);
+    
+    // At the top tier the planet keeps compounding without changing level
+    if planet.compound_level as usize + 1 >= tier_table.tiers.len() {
+        planet.prestige = planet.prestige.saturating_add(1);
+    }
+    
+    // Increase compound level while the next tier's requirements are met
+    while let Some(next) = tier_table.tiers.get(planet.compound_level as usize + 1) {
+        if planet.compound_count < next.compounds_required
+            || planet.locked_tokens < next.min_locked_tokens
+        {
+            break;
+        }
+        planet.compound_level += 1;
+    }
+    
+    // Update daily reward and name based on compound level
+    planet.name = get_tier_for_level(tier_table, planet.compound_level)?.name.clone();
+    rebase_planet(planet, config, tier_table)?;
+    
+    // Update last claim timestamp
+    planet.last_claim = get_next_last_claim(planet, config, now);
+    Ok(reward)
+}
+
+/// Tokens the reward pool must hold back to cover locked tokens
+fn required_reserve(config: &Config) -> Result<u64> {
+    math::apply_bps(config.total_locked_tokens, config.reserve_ratio_bps as u64)
+}
+
+/// Pool balance above the required reserve that can be paid out as rewards
+fn available_rewards(config: &Config, pool_balance: u64) -> Result<u64> {
+    Ok(pool_balance.saturating_sub(required_reserve(config)?))
+}
+
+/// Whether a planet can be claimed or compounded: a full interval has passed
+/// since its last claim, or it has banked rewards waiting
+fn is_reward_ready(planet: &Planet, config: &Config, now: i64) -> bool {
+    now - planet.last_claim >= config.reward_interval as i64 || planet.owed_rewards > 0
+}
+
+/// Cap a reward at what the pool can pay, emitting PoolLow when it falls short
+fn ration_reward(config: &Config, pool_balance: u64, requested: u64, now: i64) -> Result<u64> {
+    let available = available_rewards(config, pool_balance)?;
+    if requested <= available {
+        return Ok(requested);
+    }
+    
+    require!(available > 0, ErrorCode::RewardPoolDepleted);
+    emit!(PoolLow {
+        pool_balance,
+        liabilities: config.total_locked_tokens,
+        required_reserve: required_reserve(config)?,
+        requested,
+        paid: available,
+        timestamp: now,
+    });
+    Ok(available)
+}
+
+/// Push a planet's current name, symbol and URI to its NFT metadata
+fn update_planet_metadata<'info>(
+    token_metadata_program: &AccountInfo<'info>,
+    metadata: &AccountInfo<'info>,
+    program_authority: &AccountInfo<'info>,
+    authority_bump: u8,
+    planet: &Planet,
+    tier_table: &TierTable,
+) -> Result<()> {
+    let authority_seeds = &[
+        b"authority".as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&authority_seeds[..]];
+    let tier = get_tier_for_level(tier_table, planet.compound_level)?;
+    let data = get_planet_metadata(tier, planet.compound_level);
+    
+    UpdateMetadataAccountV2CpiBuilder::new(token_metadata_program)
+        .metadata(metadata)
+        .update_authority(program_authority)
+        .data(data.clone())
+        .invoke_signed(signer)?;
+    
+    msg!("NFT metadata updated for planet: {}, Symbol: {}, URI: {}", 
+         planet.name, data.symbol, data.uri);
+    Ok(())
+}
+
+/// Rewards accrued by a planet since its last claim, plus any owed rewards
+/// Per-planet rates follow the emission schedule; accumulator emissions are
+/// set directly through `Config.emission_rate`
+fn calculate_pending_reward(
+    planet: &Planet,
+    config: &Config,
+    emission_schedule: &EmissionSchedule,
+    now: i64,
+) -> Result<u64> {
+    let accrued = match config.reward_mode {
+        RewardMode::PerPlanetRate => calculate_rate_reward(planet, config, emission_schedule, now)?,
+        RewardMode::Accumulator => {
+            let accrued = math::accrued_reward(planet.shares, current_reward_per_share(config, now)?)?;
+            u64::try_from(accrued.saturating_sub(planet.reward_debt))
+                .map_err(|_| ErrorCode::MathOverflow)?
+        }
+    };
+    math::checked_add(accrued, planet.owed_rewards)
+}
+
+/// Per-planet rate rewards, accrued for at most `max_accrual_window` after the last claim
+/// In decay mode rewards then shrink linearly, reaching zero one more window later
+fn calculate_rate_reward(
+    planet: &Planet,
+    config: &Config,
+    emission_schedule: &EmissionSchedule,
+    now: i64,
+) -> Result<u64> {
+    let window = config.max_accrual_window;
+    let accrual_end = planet.last_claim.saturating_add(window);
+    let paid_until = match config.interval_mode {
+        IntervalMode::Continuous => now.min(accrual_end),
+        IntervalMode::WholeIntervals => {
+            get_next_last_claim(planet, config, now.min(accrual_end))
+        }
+    };
+    let reward = math::calculate_reward(
+        planet.locked_tokens,
+        planet.daily_reward as u64,
+        scheduled_elapsed(emission_schedule, planet.last_claim, paid_until)?,
+        get_rate_period(planet.rate_unit, config),
+    )?;
+    
+    match config.accrual_cap_mode {
+        AccrualCapMode::Hold => Ok(reward),
+        AccrualCapMode::Decay => {
+            let overdue = (now - accrual_end).clamp(0, window);
+            math::mul_div(reward, (window - overdue) as u64, window as u64)
+        }
+    }
+}
+
+/// Seconds a reward rate expressed in `rate_unit` applies to
+fn get_rate_period(rate_unit: RateUnit, config: &Config) -> u32 {
+    match rate_unit {
+        RateUnit::PerInterval => config.reward_interval,
+        RateUnit::PerDay => SECONDS_PER_DAY,
+    }
+}
+
+/// New `last_claim` after paying a planet at `now`; whole-interval mode only
+/// moves it forward by complete intervals so the leftover seconds carry over
+fn get_next_last_claim(planet: &Planet, config: &Config, now: i64) -> i64 {
+    match config.interval_mode {
+        IntervalMode::Continuous => now,
+        IntervalMode::WholeIntervals => {
+            let interval = config.reward_interval as i64;
+            planet.last_claim + (now - planet.last_claim) / interval * interval
+        }
+    }
+}
+
+/// Emission multiplier in basis points at `timestamp`, and when it next changes
+fn emission_multiplier_at(emission_schedule: &EmissionSchedule, timestamp: i64) -> (u64, Option<i64>) {
+    let segments = &emission_schedule.segments;
+    let index = segments.iter().rposition(|segment| segment.start_time <= timestamp);
+    let next_segment = segments
+        .get(index.map_or(0, |i| i + 1))
+        .map(|segment| segment.start_time);
+    
+    // Full rate until the first segment starts
+    let Some(segment) = index.map(|i| &segments[i]) else {
+        return (math::BPS_DENOMINATOR, next_segment);
+    };
+    
+    let epoch = ((timestamp - segment.start_time) / segment.epoch_duration) as u64;
+    let initial = segment.initial_multiplier_bps as u64;
+    let multiplier = match segment.curve {
+        DecayCurve::Flat => initial,
+        DecayCurve::Halving => initial.checked_shr(epoch.min(u32::MAX as u64) as u32).unwrap_or(0),
+        DecayCurve::Linear { step_bps } => initial.saturating_sub((step_bps as u64).saturating_mul(epoch)),
+        DecayCurve::Exponential { rate_bps } => {
+            initial * math::pow_bps(math::BPS_DENOMINATOR - rate_bps as u64, epoch) / math::BPS_DENOMINATOR
+        }
+    };
+    
+    // The multiplier only changes at epoch boundaries, and never again once flat or exhausted
+    let next_epoch = if segment.curve == DecayCurve::Flat || multiplier == 0 {
+        None
+    } else {
+        Some(segment.start_time.saturating_add(
+            (epoch as i64).saturating_add(1).saturating_mul(segment.epoch_duration),
+        ))
+    };
+    let next_change = match (next_epoch, next_segment) {
+        (Some(epoch_end), Some(segment_start)) => Some(epoch_end.min(segment_start)),
+        (epoch_end, segment_start) => epoch_end.or(segment_start),
+    };
+    
+    (multiplier, next_change)
+}
+
+/// Seconds between `from` and `to` weighted by the emission multiplier in basis points
+fn scheduled_elapsed(emission_schedule: &EmissionSchedule, from: i64, to: i64) -> Result<u128> {
+    let mut weighted: u128 = 0;
+    let mut timestamp = from;
+    
+    while timestamp < to {
+        let (multiplier, next_change) = emission_multiplier_at(emission_schedule, timestamp);
+        let end = next_change.map_or(to, |next| next.min(to));
+        weighted = (multiplier as u128)
+            .checked_mul((end - timestamp) as u128)
+            .and_then(|x| x.checked_add(weighted))
+            .ok_or(ErrorCode::MathOverflow)?;
+        timestamp = end;
+    }
+    
+    Ok(weighted)
+}
+
+/// Global reward index including emissions since `last_reward_time`
+fn current_reward_per_share(config: &Config, now: i64) -> Result<u128> {
+    if config.reward_mode != RewardMode::Accumulator
+        || config.total_shares == 0
+        || now <= config.last_reward_time
+    {
+        return Ok(config.acc_reward_per_share);
+    }
+    
+    let emitted = (config.emission_rate as u128)
+        .checked_mul((now - config.last_reward_time) as u128)
+        .and_then(|x| u64::try_from(x).ok())
+        .ok_or(ErrorCode::MathOverflow)?;
+    math::accumulate_reward_per_share(config.acc_reward_per_share, emitted, config.total_shares)
+}
+
+/// Bring the global reward index up to `now`; must run before any shares change
+fn update_reward_index(config: &mut Config, now: i64) -> Result<()> {
+    config.acc_reward_per_share = current_reward_per_share(config, now)?;
+    config.last_reward_time = now;
+    Ok(())
+}
+
+/// Move a planet onto the current base rate and its tier's bonus
+fn rebase_planet(planet: &mut Planet, config: &mut Config, tier_table: &TierTable) -> Result<()> {
+    let tier = get_tier_for_level(tier_table, planet.compound_level)?;
+    planet.daily_reward = get_tier_reward_bps(config, tier);
+    planet.rate_unit = config.rate_unit;
+    reweight_planet(planet, config)
+}
+
+/// Mark all of a planet's accumulator and owed rewards as paid out
+fn settle_planet_rewards(planet: &mut Planet, config: &Config) -> Result<()> {
+    planet.reward_debt = math::accrued_reward(planet.shares, config.acc_reward_per_share)?;
+    planet.owed_rewards = 0;
+    Ok(())
+}
+
+/// Move everything a planet has earned into `owed_rewards` and restart accrual,
+/// so a change to its balance or rate only applies from `now` on
+fn bank_planet_rewards(
+    planet: &mut Planet,
+    config: &mut Config,
+    emission_schedule: &EmissionSchedule,
+    now: i64,
+) -> Result<()> {
+    update_reward_index(config, now)?;
+    let earned = calculate_pending_reward(planet, config, emission_schedule, now)?;
+    settle_planet_rewards(planet, config)?;
+    planet.owed_rewards = earned;
+    planet.last_claim = get_next_last_claim(planet, config, now);
+    Ok(())
+}
+
+/// Recompute a planet's weighted shares after its locked tokens or tier changed,
+/// keeping any unsettled accumulator rewards
+fn reweight_planet(planet: &mut Planet, config: &mut Config) -> Result<()> {
+    let unsettled = math::accrued_reward(planet.shares, config.acc_reward_per_share)?
+        .saturating_sub(planet.reward_debt);
+    let shares = math::apply_bps(planet.locked_tokens, planet.daily_reward as u64)?;
+    
+    config.total_shares = math::checked_add(
+        math::checked_sub(config.total_shares, planet.shares)?,
+        shares,
+    )?;
+    planet.shares = shares;
+    planet.reward_debt = math::accrued_reward(shares, config.acc_reward_per_share)?
+        .saturating_sub(unsettled);
+    Ok(())
+}
+
+/// Symbol prefix for planet NFTs (Metaplex limits symbols to 10 characters)
+const PLANET_SYMBOL: &str = "UNIV-PL";
+
+/// Base URI for planet NFT metadata
+const PLANET_METADATA_BASE_URI: &str = "https://universe-solana.com/metadata";
+
+/// Maximum number of planet tiers
+const MAX_TIERS: usize = 16;
+const MAX_TIER_NAME_LENGTH: usize = mpl_token_metadata::MAX_NAME_LENGTH;
+const MAX_TIER_URI_LENGTH: usize = mpl_token_metadata::MAX_URI_LENGTH;
+const TIER_SPACE: usize = 4 + MAX_TIER_NAME_LENGTH + 2 + 4 + 8 + 4 + MAX_TIER_URI_LENGTH;
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// Longest an unclaimed planet can keep accruing rewards
+const MAX_ACCRUAL_WINDOW: i64 = 30 * SECONDS_PER_DAY as i64;
+
+/// Maximum number of emission schedule segments
+const MAX_EMISSION_SEGMENTS: usize = 8;
+const EMISSION_SEGMENT_SPACE: usize = 8 + 8 + 2 + 1 + 2;
+
+fn get_planet_symbol_for_level(compound_level: u8) -> String {
+    format!("{}-{}", PLANET_SYMBOL, compound_level)
+}
+
+/// Build the Metaplex metadata for a planet at its current tier
+fn get_planet_metadata(tier: &Tier, compound_level: u8) -> DataV2 {
+    DataV2 {
+        name: tier.name.clone(),
+        symbol: get_planet_symbol_for_level(compound_level),
+        uri: tier.metadata_uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
     }
 }
 
+fn get_tier_for_level(tier_table: &TierTable, compound_level: u8) -> Result<&Tier> {
+    tier_table
+        .tiers
+        .get(compound_level as usize)
+        .ok_or(ErrorCode::InvalidCompoundLevel.into())
+}
+
+/// Reward rate of a tier in basis points per `Config.rate_unit`: the base rate
+/// for that unit plus the tier's daily bonus, scaled down to one interval if needed
+fn get_tier_reward_bps(config: &Config, tier: &Tier) -> u16 {
+    match config.rate_unit {
+        RateUnit::PerDay => config.daily_rate_bps.saturating_add(tier.bonus_bps),
+        RateUnit::PerInterval => {
+            let bonus = tier.bonus_bps as u64 * config.reward_interval as u64 / SECONDS_PER_DAY as u64;
+            config
+                .interval_rate_bps
+                .saturating_add(u16::try_from(bonus).unwrap_or(u16::MAX))
+        }
+    }
+}
+
+/// Default tiers used when the tier table is created; each level adds 1% a day
+/// to the base rate (4% on Earth up to 14% on the Sun)
+fn get_default_tiers() -> Vec<Tier> {
+    [
+        "Earth", "Moon", "Mercury", "Venus", "Mars", "Jupiter",
+        "Saturn", "Uranus", "Neptune", "Pluto", "Sun",
+    ]
+    .iter()
+    .enumerate()
+    .map(|(level, name)| Tier {
+        name: name.to_string(),
+        bonus_bps: level as u16 * 100,
+        compounds_required: level as u32,
+        min_locked_tokens: 0,
+        metadata_uri: format!("{}/tiers/{}.json", PLANET_METADATA_BASE_URI, level),
+    })
+    .collect()
+}
+
 /*************************************************************
  * NOTES ON PINKSALE INTEGRATION
  * 
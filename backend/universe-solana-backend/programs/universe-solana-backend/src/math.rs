@@ -0,0 +1,461 @@
+use anchor_lang::prelude::*;
+
+use crate::ErrorCode;
+
+/********************************************
+ * REWARD MATH
+ *
+ * Checked fixed-point helpers shared by every payout path.
+ * Token amounts are u64 base units (9 decimals), rates are
+ * basis points and all intermediate products use u128, so
+ * results are exact to the base unit and can be reproduced
+ * off-chain. Every division rounds down.
+ ********************************************/
+
+/// 100% expressed in basis points
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Convert a whole-percent rate (as stored in Config) to basis points
+pub fn percent_to_bps(percent: u8) -> u64 {
+    percent as u64 * 100
+}
+
+/// Compute `value * numerator / denominator` without intermediate overflow
+pub fn mul_div(value: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    require!(denominator != 0, ErrorCode::DivisionByZero);
+
+    let product = (value as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    u64::try_from(product / denominator as u128).map_err(|_| ErrorCode::MathOverflow.into())
+}
+
+/// Take `bps` basis points of `amount`
+pub fn apply_bps(amount: u64, bps: u64) -> Result<u64> {
+    mul_div(amount, bps, BPS_DENOMINATOR)
+}
+
+/// Take a whole-percent `rate` of `amount`
+pub fn apply_percent(amount: u64, rate: u8) -> Result<u64> {
+    apply_bps(amount, percent_to_bps(rate))
+}
+
+/// Reward earned by `locked_tokens` at `rate_bps` per `interval` seconds,
+/// including partial intervals. `weighted_elapsed` is elapsed seconds
+/// multiplied by the emission multiplier in basis points.
+pub fn calculate_reward(locked_tokens: u64, rate_bps: u64, weighted_elapsed: u128, interval: u32) -> Result<u64> {
+    require!(interval != 0, ErrorCode::DivisionByZero);
+
+    let numerator = (locked_tokens as u128)
+        .checked_mul(rate_bps as u128)
+        .and_then(|x| x.checked_mul(weighted_elapsed))
+        .ok_or(ErrorCode::MathOverflow)?;
+    let denominator = (BPS_DENOMINATOR as u128) * (BPS_DENOMINATOR as u128) * (interval as u128);
+
+    u64::try_from(numerator / denominator).map_err(|_| ErrorCode::MathOverflow.into())
+}
+
+/// Raise a basis-point factor to `exp`, rounding down after every multiplication
+pub fn pow_bps(factor_bps: u64, mut exp: u64) -> u64 {
+    let mut result = BPS_DENOMINATOR;
+    let mut base = factor_bps.min(BPS_DENOMINATOR);
+
+    while exp > 0 && result > 0 {
+        if exp & 1 == 1 {
+            result = result * base / BPS_DENOMINATOR;
+        }
+        base = base * base / BPS_DENOMINATOR;
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Portion of `total` vested after `elapsed` seconds of a linear `duration`
+pub fn vested_amount(total: u64, elapsed: i64, duration: i64) -> Result<u64> {
+    require!(duration > 0, ErrorCode::DivisionByZero);
+
+    let elapsed = elapsed.clamp(0, duration);
+    mul_div(total, elapsed as u64, duration as u64)
+}
+
+pub fn checked_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or(ErrorCode::MathOverflow.into())
+}
+
+pub fn checked_sub(a: u64, b: u64) -> Result<u64> {
+    a.checked_sub(b).ok_or(ErrorCode::MathOverflow.into())
+}
+
+/// Fixed-point scale of the global reward index
+pub const ACC_PRECISION: u128 = 1_000_000_000_000;
+
+/// Advance a reward-per-share index by `emitted` tokens spread over `total_shares`
+pub fn accumulate_reward_per_share(acc_reward_per_share: u128, emitted: u64, total_shares: u64) -> Result<u128> {
+    require!(total_shares != 0, ErrorCode::DivisionByZero);
+
+    let increment = (emitted as u128)
+        .checked_mul(ACC_PRECISION)
+        .ok_or(ErrorCode::MathOverflow)?
+        / total_shares as u128;
+
+    acc_reward_per_share.checked_add(increment).ok_or(ErrorCode::MathOverflow.into())
+}
+
+/// Rewards earned by `shares` since the index was zero, before subtracting reward debt
+pub fn accrued_reward(shares: u64, acc_reward_per_share: u128) -> Result<u128> {
+    (shares as u128)
+        .checked_mul(acc_reward_per_share)
+        .map(|x| x / ACC_PRECISION)
+        .ok_or(ErrorCode::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calculate_rate_reward, emission_multiplier_at, get_next_last_claim, get_tier_reward_bps,
+        scheduled_elapsed, AccrualCapMode, Config, DecayCurve, DowngradeRule, EmissionSchedule,
+        EmissionSegment, IntervalMode, Planet, RateUnit, RewardMode, Tier,
+    };
+
+    const UNIV: u64 = 1_000_000_000;
+    const INTERVAL: u32 = 28_800;
+
+    fn is_error(result: Result<impl std::fmt::Debug>, code: ErrorCode) -> bool {
+        result.unwrap_err() == code.into()
+    }
+
+    #[test]
+    fn mul_div_rounds_down() {
+        assert_eq!(mul_div(10, 1, 3).unwrap(), 3);
+        assert_eq!(mul_div(2, 2, 3).unwrap(), 1);
+        assert_eq!(mul_div(0, u64::MAX, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn mul_div_has_no_intermediate_overflow() {
+        assert_eq!(mul_div(u64::MAX, u64::MAX, u64::MAX).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn mul_div_errors() {
+        assert!(is_error(mul_div(u64::MAX, 2, 1), ErrorCode::MathOverflow));
+        assert!(is_error(mul_div(1, 1, 0), ErrorCode::DivisionByZero));
+    }
+
+    #[test]
+    fn apply_rates() {
+        assert_eq!(apply_bps(1_000, 333).unwrap(), 33);
+        assert_eq!(apply_bps(1_000, BPS_DENOMINATOR).unwrap(), 1_000);
+        assert_eq!(apply_percent(1_000 * UNIV, 4).unwrap(), 40 * UNIV);
+    }
+
+    #[test]
+    fn calculate_reward_full_and_partial_intervals() {
+        let full = INTERVAL as u128 * BPS_DENOMINATOR as u128;
+        assert_eq!(calculate_reward(1_000 * UNIV, 400, full, INTERVAL).unwrap(), 40 * UNIV);
+        assert_eq!(calculate_reward(1_000 * UNIV, 400, full / 2, INTERVAL).unwrap(), 20 * UNIV);
+        assert_eq!(calculate_reward(1_000 * UNIV, 400, full * 3, INTERVAL).unwrap(), 120 * UNIV);
+    }
+
+    #[test]
+    fn calculate_reward_applies_multiplier() {
+        // Half the emission multiplier for a full interval pays half the reward
+        let half = INTERVAL as u128 * 5_000;
+        assert_eq!(calculate_reward(1_000 * UNIV, 400, half, INTERVAL).unwrap(), 20 * UNIV);
+    }
+
+    #[test]
+    fn calculate_reward_rounds_down() {
+        assert_eq!(calculate_reward(1, 1, 1, INTERVAL).unwrap(), 0);
+        // 1,000 base units at 4% for one second of an 8 hour interval
+        assert_eq!(calculate_reward(1_000, 400, BPS_DENOMINATOR as u128, INTERVAL).unwrap(), 0);
+        assert_eq!(calculate_reward(7, 5_000, 10_000 * 28_800, INTERVAL).unwrap(), 3);
+    }
+
+    #[test]
+    fn calculate_reward_errors() {
+        assert!(is_error(calculate_reward(1, 1, 1, 0), ErrorCode::DivisionByZero));
+        assert!(is_error(
+            calculate_reward(u64::MAX, u64::MAX, u128::MAX, INTERVAL),
+            ErrorCode::MathOverflow
+        ));
+        // Fits in u128 but not in the u64 result
+        assert!(is_error(
+            calculate_reward(u64::MAX, BPS_DENOMINATOR, 2 * BPS_DENOMINATOR as u128, 1),
+            ErrorCode::MathOverflow
+        ));
+    }
+
+    #[test]
+    fn pow_bps_edge_cases() {
+        assert_eq!(pow_bps(0, 0), BPS_DENOMINATOR);
+        assert_eq!(pow_bps(0, 1), 0);
+        assert_eq!(pow_bps(0, u64::MAX), 0);
+        assert_eq!(pow_bps(BPS_DENOMINATOR, 0), BPS_DENOMINATOR);
+        assert_eq!(pow_bps(BPS_DENOMINATOR, u64::MAX), BPS_DENOMINATOR);
+        // Factors above 100% are clamped
+        assert_eq!(pow_bps(2 * BPS_DENOMINATOR, 3), BPS_DENOMINATOR);
+    }
+
+    #[test]
+    fn pow_bps_rounds_down() {
+        assert_eq!(pow_bps(5_000, 1), 5_000);
+        assert_eq!(pow_bps(5_000, 2), 2_500);
+        assert_eq!(pow_bps(5_000, 3), 1_250);
+        assert_eq!(pow_bps(9_999, 2), 9_998);
+        assert_eq!(pow_bps(3_333, 2), 1_110);
+    }
+
+    #[test]
+    fn pow_bps_large_exponents() {
+        assert_eq!(pow_bps(9_999, u64::MAX), 0);
+        assert_eq!(pow_bps(5_000, 64), 0);
+        assert_eq!(pow_bps(5_000, 1_000_000), 0);
+    }
+
+    #[test]
+    fn vested_amount_is_linear_and_clamped() {
+        assert_eq!(vested_amount(1_000, 250, 1_000).unwrap(), 250);
+        assert_eq!(vested_amount(1_000, 1, 3).unwrap(), 333);
+        assert_eq!(vested_amount(1_000, -5, 1_000).unwrap(), 0);
+        assert_eq!(vested_amount(1_000, 5_000, 1_000).unwrap(), 1_000);
+        assert!(is_error(vested_amount(1_000, 1, 0), ErrorCode::DivisionByZero));
+    }
+
+    #[test]
+    fn reward_index_accumulates_and_rounds_down() {
+        let acc = accumulate_reward_per_share(0, 100, 3).unwrap();
+        assert_eq!(acc, 33_333_333_333_333);
+        // Shares together never receive more than was emitted
+        assert_eq!(accrued_reward(3, acc).unwrap(), 99);
+        assert_eq!(accrued_reward(1, acc).unwrap(), 33);
+
+        let acc = accumulate_reward_per_share(acc, 200, 3).unwrap();
+        assert_eq!(accrued_reward(3, acc).unwrap(), 299);
+    }
+
+    #[test]
+    fn reward_index_errors() {
+        assert!(is_error(accumulate_reward_per_share(0, 1, 0), ErrorCode::DivisionByZero));
+        assert!(is_error(
+            accumulate_reward_per_share(u128::MAX, 1, 1),
+            ErrorCode::MathOverflow
+        ));
+        assert!(is_error(accrued_reward(u64::MAX, u128::MAX), ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn checked_helpers() {
+        assert_eq!(checked_add(1, 2).unwrap(), 3);
+        assert_eq!(checked_sub(3, 2).unwrap(), 1);
+        assert!(is_error(checked_add(u64::MAX, 1), ErrorCode::MathOverflow));
+        assert!(is_error(checked_sub(0, 1), ErrorCode::MathOverflow));
+    }
+
+    const DAY: i64 = 24 * 60 * 60;
+    const WINDOW: i64 = 7 * DAY;
+
+    fn segment(start_time: i64, epoch_duration: i64, curve: DecayCurve) -> EmissionSegment {
+        EmissionSegment {
+            start_time,
+            epoch_duration,
+            initial_multiplier_bps: BPS_DENOMINATOR as u16,
+            curve,
+        }
+    }
+
+    fn schedule(segments: Vec<EmissionSegment>) -> EmissionSchedule {
+        EmissionSchedule { bump: 0, segments }
+    }
+
+    fn config(accrual_cap_mode: AccrualCapMode, interval_mode: IntervalMode) -> Config {
+        Config {
+            total_supply: 0,
+            planet_creation_cost: 1_000 * UNIV,
+            max_planets_per_user: 10,
+            reward_rate: 3,
+            reward_interval: INTERVAL,
+            token_mint: Pubkey::default(),
+            admin: Pubkey::default(),
+            reward_pool: Pubkey::default(),
+            team_wallet: Pubkey::default(),
+            marketing_wallet: Pubkey::default(),
+            liquidity_wallet: Pubkey::default(),
+            authority_bump: 0,
+            transaction_tax_rate: 0,
+            liquidity_tax_rate: 0,
+            reward_tax_rate: 0,
+            nft_transfer_tax_rate: 0,
+            team_nft_tax_rate: 0,
+            reward_nft_tax_rate: 0,
+            vesting_start_time: 0,
+            ecosystem_vesting_duration: 0,
+            treasury_vesting_duration: 0,
+            planet_count: 0,
+            bump: 0,
+            early_exit_penalty_rate: 0,
+            early_exit_period: 0,
+            downgrade_rule: DowngradeRule::OneLevel,
+            total_locked_tokens: 0,
+            reserve_ratio_bps: 0,
+            reward_mode: RewardMode::PerPlanetRate,
+            emission_rate: 0,
+            acc_reward_per_share: 0,
+            total_shares: 0,
+            last_reward_time: 0,
+            max_accrual_window: WINDOW,
+            accrual_cap_mode,
+            interval_mode,
+            rate_unit: RateUnit::PerInterval,
+            interval_rate_bps: 133,
+            daily_rate_bps: 400,
+        }
+    }
+
+    /// 1,000 $UNIV planet earning 4% per `rate_unit`, last claimed at 0
+    fn planet(rate_unit: RateUnit) -> Planet {
+        Planet {
+            compound_level: 0,
+            daily_reward: 400,
+            last_claim: 0,
+            locked_tokens: 1_000 * UNIV,
+            name: String::new(),
+            planet_id: 0,
+            mint: Pubkey::default(),
+            bump: 0,
+            created_at: 0,
+            compound_count: 0,
+            prestige: 0,
+            shares: 0,
+            reward_debt: 0,
+            rate_unit,
+            owed_rewards: 0,
+        }
+    }
+
+    #[test]
+    fn emission_multiplier_halving() {
+        let schedule = schedule(vec![segment(1_000, 100, DecayCurve::Halving)]);
+        assert_eq!(emission_multiplier_at(&schedule, 999), (BPS_DENOMINATOR, Some(1_000)));
+        assert_eq!(emission_multiplier_at(&schedule, 1_000), (10_000, Some(1_100)));
+        assert_eq!(emission_multiplier_at(&schedule, 1_150), (5_000, Some(1_200)));
+        assert_eq!(emission_multiplier_at(&schedule, 1_300), (1_250, Some(1_400)));
+        // Exhausted after 14 halvings and never changes again
+        assert_eq!(emission_multiplier_at(&schedule, 2_400), (0, None));
+    }
+
+    #[test]
+    fn emission_multiplier_linear() {
+        let schedule = schedule(vec![segment(0, 100, DecayCurve::Linear { step_bps: 3_000 })]);
+        assert_eq!(emission_multiplier_at(&schedule, 150), (7_000, Some(200)));
+        assert_eq!(emission_multiplier_at(&schedule, 399), (1_000, Some(400)));
+        assert_eq!(emission_multiplier_at(&schedule, 400), (0, None));
+        assert_eq!(emission_multiplier_at(&schedule, 10_000), (0, None));
+    }
+
+    #[test]
+    fn emission_multiplier_exponential() {
+        let schedule = schedule(vec![segment(0, 100, DecayCurve::Exponential { rate_bps: 1_000 })]);
+        assert_eq!(emission_multiplier_at(&schedule, 0), (10_000, Some(100)));
+        assert_eq!(emission_multiplier_at(&schedule, 100), (9_000, Some(200)));
+        assert_eq!(emission_multiplier_at(&schedule, 250), (8_100, Some(300)));
+    }
+
+    #[test]
+    fn emission_multiplier_crosses_segments() {
+        let mut flat = segment(1_150, 100, DecayCurve::Flat);
+        flat.initial_multiplier_bps = 2_000;
+        let schedule = schedule(vec![segment(1_000, 100, DecayCurve::Halving), flat]);
+        // The next segment starts before the current epoch ends
+        assert_eq!(emission_multiplier_at(&schedule, 1_100), (5_000, Some(1_150)));
+        assert_eq!(emission_multiplier_at(&schedule, 1_150), (2_000, None));
+        assert_eq!(emission_multiplier_at(&schedule, 1_000_000), (2_000, None));
+    }
+
+    #[test]
+    fn scheduled_elapsed_weights_each_segment() {
+        let mut flat = segment(1_150, 100, DecayCurve::Flat);
+        flat.initial_multiplier_bps = 2_000;
+        let schedule = schedule(vec![segment(1_000, 100, DecayCurve::Halving), flat]);
+        assert_eq!(scheduled_elapsed(&schedule, 500, 500).unwrap(), 0);
+        assert_eq!(scheduled_elapsed(&schedule, 900, 1_000).unwrap(), 100 * 10_000);
+        // Full rate, first epoch, half of the halved epoch, then the flat segment
+        assert_eq!(
+            scheduled_elapsed(&schedule, 900, 1_250).unwrap(),
+            100 * 10_000 + 100 * 10_000 + 50 * 5_000 + 100 * 2_000
+        );
+        // An empty schedule pays full rate
+        assert_eq!(scheduled_elapsed(&self::schedule(vec![]), 0, 10).unwrap(), 10 * 10_000);
+    }
+
+    #[test]
+    fn rate_reward_per_interval_and_per_day() {
+        let config = config(AccrualCapMode::Hold, IntervalMode::Continuous);
+        let schedule = schedule(vec![]);
+        let now = INTERVAL as i64;
+        assert_eq!(calculate_rate_reward(&planet(RateUnit::PerInterval), &config, &schedule, now).unwrap(), 40 * UNIV);
+        assert_eq!(calculate_rate_reward(&planet(RateUnit::PerDay), &config, &schedule, now).unwrap(), 40 * UNIV / 3);
+        assert_eq!(calculate_rate_reward(&planet(RateUnit::PerDay), &config, &schedule, DAY).unwrap(), 40 * UNIV);
+    }
+
+    #[test]
+    fn rate_reward_hold_cap() {
+        let config = config(AccrualCapMode::Hold, IntervalMode::Continuous);
+        let schedule = schedule(vec![]);
+        let planet = planet(RateUnit::PerInterval);
+        // 21 intervals fit in the 7 day window
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, WINDOW).unwrap(), 840 * UNIV);
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, WINDOW + DAY).unwrap(), 840 * UNIV);
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, 10 * WINDOW).unwrap(), 840 * UNIV);
+    }
+
+    #[test]
+    fn rate_reward_decay_after_cap() {
+        let config = config(AccrualCapMode::Decay, IntervalMode::Continuous);
+        let schedule = schedule(vec![]);
+        let planet = planet(RateUnit::PerInterval);
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, WINDOW).unwrap(), 840 * UNIV);
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, WINDOW + WINDOW / 2).unwrap(), 420 * UNIV);
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, 2 * WINDOW).unwrap(), 0);
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, 5 * WINDOW).unwrap(), 0);
+    }
+
+    #[test]
+    fn whole_intervals_carry_leftover_time() {
+        let config = config(AccrualCapMode::Hold, IntervalMode::WholeIntervals);
+        let schedule = schedule(vec![]);
+        let mut planet = planet(RateUnit::PerInterval);
+        let interval = INTERVAL as i64;
+
+        // Half an interval is not paid but is kept for the next claim
+        let now = interval + interval / 2;
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, now).unwrap(), 40 * UNIV);
+        planet.last_claim = get_next_last_claim(&planet, &config, now);
+        assert_eq!(planet.last_claim, interval);
+
+        assert_eq!(calculate_rate_reward(&planet, &config, &schedule, 2 * interval).unwrap(), 40 * UNIV);
+        assert_eq!(get_next_last_claim(&planet, &config, 2 * interval + 1), 2 * interval);
+
+        let continuous = self::config(AccrualCapMode::Hold, IntervalMode::Continuous);
+        assert_eq!(get_next_last_claim(&planet, &continuous, now), now);
+    }
+
+    #[test]
+    fn tier_rate_follows_rate_unit() {
+        let mut config = config(AccrualCapMode::Hold, IntervalMode::Continuous);
+        let tier = Tier {
+            name: String::new(),
+            bonus_bps: 300,
+            compounds_required: 0,
+            min_locked_tokens: 0,
+            metadata_uri: String::new(),
+        };
+        // The daily bonus is scaled to one 8 hour interval
+        assert_eq!(get_tier_reward_bps(&config, &tier), 133 + 100);
+        config.rate_unit = RateUnit::PerDay;
+        assert_eq!(get_tier_reward_bps(&config, &tier), 400 + 300);
+    }
+}